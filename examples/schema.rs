@@ -4,8 +4,10 @@ use std::fs::create_dir_all;
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
 use bc::msg::{
-    AccessLevel, ContractInfoResponse, DataItem, DataVersion, ExecuteMsg, InstantiateMsg,
-    NumTokensResponse, OwnerOfResponse, QueryMsg, TokenInfoResponse,
+    AccessLevel, AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ContractInfoResponse,
+    DataItem, DataVersion, ExecuteMsg, InstantiateMsg, MigrateMsg, NftInfoResponse,
+    NumTokensResponse, OperatorResponse, OperatorsResponse, OwnerOfResponse, QueryMsg,
+    TokenInfoResponse, TokensResponse,
 };
 
 fn main() {
@@ -18,12 +20,20 @@ fn main() {
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(MigrateMsg), &out_dir);
 
     // Export response schemas
     export_schema(&schema_for!(OwnerOfResponse), &out_dir);
     export_schema(&schema_for!(TokenInfoResponse), &out_dir);
     export_schema(&schema_for!(NumTokensResponse), &out_dir);
     export_schema(&schema_for!(ContractInfoResponse), &out_dir);
+    export_schema(&schema_for!(ApprovalResponse), &out_dir);
+    export_schema(&schema_for!(ApprovalsResponse), &out_dir);
+    export_schema(&schema_for!(OperatorResponse), &out_dir);
+    export_schema(&schema_for!(OperatorsResponse), &out_dir);
+    export_schema(&schema_for!(NftInfoResponse<DataItem>), &out_dir);
+    export_schema(&schema_for!(AllNftInfoResponse<DataItem>), &out_dir);
+    export_schema(&schema_for!(TokensResponse), &out_dir);
 
     // Export data structure schemas
     export_schema(&schema_for!(DataItem), &out_dir);
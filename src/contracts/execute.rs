@@ -1,22 +1,39 @@
 use crate::error::ContractError;
 use crate::helpers::{
-    ensure_can_execute_proposal, ensure_can_vote_on_proposal, ensure_dao_member,
-    ensure_proposal_exists, is_dao_member, validate_dao_config, validate_voting_period,
+    advance_vote_phase, apply_vote, bps_share, citation_denom, ensure_accepted_denom,
+    ensure_can_commit_vote, ensure_can_execute_proposal, ensure_can_vote_on_proposal,
+    ensure_contract_owner, ensure_dao_member, ensure_denoms_removable, ensure_in_reveal_phase,
+    ensure_open_voting, ensure_proposal_exists, is_dao_member, is_proposal_expired,
+    is_reveal_period_over, is_review_expired, must_pay, nonpayable, normalize_commitment,
+    normalize_doi, quorum_reached, record_reputation, reputation_at, resolve_delegated_power,
+    review_commitment, take_payment, tally_votes, threshold_reached, total_voted,
+    update_listed_denoms, validate_dao_config, validate_denoms, validate_execution_delay,
+    validate_fee_config, validate_member_weight, validate_proposal_status_transition,
+    validate_quorum, validate_reputation_config, validate_reveal_period, validate_review_config,
+    validate_veto_council, validate_voting_period, vote_commitment, ReputationEvent,
+    MAX_REVIEW_SCORE,
 };
 use crate::msg::{
-    AccessLevel, Citation, DaoConfig, DataItem, DataVersion, ExecuteMsg, ExecutionData,
-    MemberAction, Proposal, ProposalStatus, ProposalType, Vote, VoteChoice, VoteCount,
+    AccessLevel, Citation, CitationEdge, DaoConfig, DataItem, DataVersion, DepositStatus,
+    ExecuteMsg, ExecutionData, FeeConfig, MemberAction, PaymentConfig, PendingOwner, Proposal,
+    ProposalDeposit, ProposalStatus, ProposalType, ReputationConfig, Review, ReviewAssignment,
+    ReviewBounty, ReviewBountyStatus, ReviewConfig, ReviewConfigMsg, ReviewRecommendation,
+    VetoCouncil, VetoCouncilMsg, Vote, VoteChoice, VoteCount, VotingWeightSource,
 };
 use crate::state::{
-    ACCESS_CONTROLS, AUTHORIZED_USERS, BASE_CITATION_FEE, CITATIONS, CONTRACT_OWNER, DAO_CONFIG,
-    DAO_MEMBERS, DATA_ITEMS, DATA_VERSIONS, OPERATOR_APPROVALS, PAPER_DOIS, PROPOSALS,
-    PROPOSAL_COUNTER, TOKEN_APPROVALS, TOKEN_COUNT, TOKEN_ID_COUNTER, TOKEN_OWNERS, VOTES,
-    VOTE_COUNTS,
+    ACCESS_CONTROLS, APPROVED_PAPERS, AUTHORIZED_USERS, CITATIONS, CITATION_COUNTS, CITATION_EDGES,
+    CONTRACT_OWNER, DAO_CONFIG, DAO_MEMBERS, DATA_ITEMS, DATA_VERSIONS, DELEGATIONS, DOI_INDEX,
+    FEE_CONFIG, OPERATOR_APPROVALS, PAPER_DOIS, PAYMENT_CONFIG, PENDING_DISPATCH, PENDING_OWNER,
+    PROPOSALS, PROPOSAL_COUNTER, PROPOSAL_DELEGATIONS, PROPOSAL_VOTERS, REVIEWS,
+    REVIEW_ASSIGNMENTS, REVIEW_COMMITMENTS, REVIEW_NO_SHOWS, TOKEN_APPROVALS, TOKEN_COUNT,
+    TOKEN_ID_COUNTER, TOKEN_OWNERS, TREASURY, VETOES, VOTES, VOTE_COMMITMENTS, VOTE_COUNTS,
+    VOTE_NO_SHOWS,
 };
 use cosmwasm_std::{
-    entry_point, Addr, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response,
-    StdResult, Uint128,
+    entry_point, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env,
+    MessageInfo, Response, StdResult, SubMsg, Uint128, WasmMsg,
 };
+use cw721::{Cw721ReceiveMsg, Expiration};
 
 #[entry_point]
 pub fn execute(
@@ -28,12 +45,40 @@ pub fn execute(
     match msg {
         ExecuteMsg::RequestAccess { token_id } => execute_request_access(deps, env, info, token_id),
 
+        ExecuteMsg::CreateDataItem {
+            ipfs_hash,
+            metadata_uri,
+            price,
+            denom,
+            is_public,
+        } => execute_create_data_item(
+            deps,
+            env,
+            info,
+            ipfs_hash,
+            metadata_uri,
+            price,
+            denom,
+            is_public,
+        ),
+
         ExecuteMsg::UpdateDataItem {
             token_id,
             new_ipfs_hash,
             new_metadata_uri,
         } => execute_update_data_item(deps, env, info, token_id, new_ipfs_hash, new_metadata_uri),
 
+        ExecuteMsg::UpdateDataItemTerms {
+            token_id,
+            price,
+            denom,
+            is_public,
+        } => execute_update_data_item_terms(deps, env, info, token_id, price, denom, is_public),
+
+        ExecuteMsg::RetireData { token_id, retire } => {
+            execute_retire_data(deps, env, info, token_id, retire)
+        }
+        ExecuteMsg::Burn { token_id } => execute_burn(deps, env, info, token_id),
         ExecuteMsg::FreezeData { token_id, freeze } => {
             execute_freeze_data(deps, env, info, token_id, freeze)
         }
@@ -49,15 +94,38 @@ pub fn execute(
             token_id,
         } => execute_transfer_nft(deps, env, info, recipient, token_id),
 
-        ExecuteMsg::Approve { spender, token_id } => {
-            execute_approve(deps, env, info, spender, token_id)
+        ExecuteMsg::SendNft {
+            contract,
+            token_id,
+            msg,
+        } => execute_send_nft(deps, env, info, contract, token_id, msg),
+
+        ExecuteMsg::Approve {
+            spender,
+            token_id,
+            expires,
+        } => execute_approve(deps, env, info, spender, token_id, expires),
+
+        ExecuteMsg::Revoke { spender, token_id } => {
+            execute_revoke(deps, env, info, spender, token_id)
         }
 
-        ExecuteMsg::ApproveAll { operator } => execute_approve_all(deps, env, info, operator),
+        ExecuteMsg::ApproveAll { operator, expires } => {
+            execute_approve_all(deps, env, info, operator, expires)
+        }
 
         ExecuteMsg::RevokeAll { operator } => execute_revoke_all(deps, env, info, operator),
 
-        ExecuteMsg::CitePaper { paper_id } => execute_cite_paper(deps, env, info, paper_id),
+        ExecuteMsg::ProposeNewOwner { new_owner, expiry } => {
+            execute_propose_new_owner(deps, env, info, new_owner, expiry)
+        }
+        ExecuteMsg::AcceptOwnership {} => execute_accept_ownership(deps, env, info),
+        ExecuteMsg::RenounceOwnership {} => execute_renounce_ownership(deps, env, info),
+
+        ExecuteMsg::CitePaper {
+            paper_id,
+            citing_paper_id,
+        } => execute_cite_paper(deps, env, info, paper_id, citing_paper_id),
 
         ExecuteMsg::CreatePaperItem {
             ipfs_hash,
@@ -70,10 +138,6 @@ pub fn execute(
             new_ipfs_hash,
         } => execute_submit_correction(deps, env, info, original_paper_id, new_ipfs_hash),
 
-        ExecuteMsg::SetBaseCitationFee { fee } => {
-            execute_set_base_citation_fee(deps, env, info, fee)
-        }
-
         // DAO 治理消息
         ExecuteMsg::SubmitArticleProposal {
             ipfs_hash,
@@ -81,6 +145,7 @@ pub fn execute(
             metadata_uri,
             title,
             description,
+            review_bounty,
         } => execute_submit_article_proposal(
             deps,
             env,
@@ -90,11 +155,13 @@ pub fn execute(
             metadata_uri,
             title,
             description,
+            review_bounty,
         ),
 
         ExecuteMsg::SubmitMemberProposal {
             member_address,
             action,
+            weight,
             title,
             description,
         } => execute_submit_member_proposal(
@@ -103,6 +170,22 @@ pub fn execute(
             info,
             member_address,
             action,
+            weight,
+            title,
+            description,
+        ),
+
+        ExecuteMsg::SubmitMemberWeightProposal {
+            member_address,
+            weight,
+            title,
+            description,
+        } => execute_submit_member_weight_proposal(
+            deps,
+            env,
+            info,
+            member_address,
+            weight,
             title,
             description,
         ),
@@ -111,15 +194,41 @@ pub fn execute(
             proposal_id,
             choice,
         } => execute_vote_on_proposal(deps, env, info, proposal_id, choice),
+        ExecuteMsg::CommitVote {
+            proposal_id,
+            commitment,
+        } => execute_commit_vote(deps, env, info, proposal_id, commitment),
+        ExecuteMsg::RevealVote {
+            proposal_id,
+            choice,
+            salt,
+        } => execute_reveal_vote(deps, env, info, proposal_id, choice, salt),
 
         ExecuteMsg::ExecuteProposal { proposal_id } => {
             execute_proposal(deps, env, info, proposal_id)
         }
 
+        ExecuteMsg::FinalizeProposal { proposal_id } => {
+            execute_finalize_proposal(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::ClaimDeposit { proposal_id } => {
+            execute_claim_deposit(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::Delegate { to } => execute_delegate(deps, env, info, to),
+        ExecuteMsg::Undelegate {} => execute_undelegate(deps, env, info),
         ExecuteMsg::UpdateDaoConfig {
             voting_period,
             approval_threshold,
             min_members,
+            quorum,
+            proposal_deposit,
+            slash_spam_deposits,
+            execution_delay,
+            veto_council,
+            review_config,
+            vote_reveal_period,
+            reputation_config,
+            voting_weight_source,
         } => execute_update_dao_config(
             deps,
             env,
@@ -127,8 +236,181 @@ pub fn execute(
             voting_period,
             approval_threshold,
             min_members,
+            quorum,
+            proposal_deposit,
+            slash_spam_deposits,
+            execution_delay,
+            veto_council,
+            review_config,
+            vote_reveal_period,
+            reputation_config,
+            voting_weight_source,
+        ),
+        ExecuteMsg::SubmitCustomProposal {
+            title,
+            description,
+            msgs,
+        } => execute_submit_custom_proposal(deps, env, info, title, description, msgs),
+        ExecuteMsg::SubmitTreasurySpendProposal {
+            recipient,
+            amount,
+            title,
+            description,
+        } => execute_submit_treasury_spend_proposal(
+            deps,
+            env,
+            info,
+            recipient,
+            amount,
+            title,
+            description,
+        ),
+        ExecuteMsg::WithdrawProposal { proposal_id } => {
+            execute_withdraw_proposal(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::AmendProposal {
+            proposal_id,
+            execution_data,
+        } => execute_amend_proposal(deps, env, info, proposal_id, execution_data),
+        ExecuteMsg::VetoProposal {
+            proposal_id,
+            reason,
+        } => execute_veto_proposal(deps, env, info, proposal_id, reason),
+
+        ExecuteMsg::SubmitBurnProposal {
+            token_id,
+            title,
+            description,
+        } => execute_submit_burn_proposal(deps, env, info, token_id, title, description),
+        ExecuteMsg::UpdateAcceptedDenoms { denoms } => {
+            execute_update_accepted_denoms(deps, env, info, denoms)
+        }
+        ExecuteMsg::UpdateFees {
+            base_citation_fee,
+            citation_dao_share_bps,
+            access_protocol_fee_bps,
+        } => execute_update_fees(
+            deps,
+            env,
+            info,
+            base_citation_fee,
+            citation_dao_share_bps,
+            access_protocol_fee_bps,
+        ),
+        ExecuteMsg::AssignReviewer {
+            proposal_id,
+            reviewer,
+        } => execute_assign_reviewer(deps, env, info, proposal_id, reviewer),
+        ExecuteMsg::CommitReview {
+            proposal_id,
+            commitment,
+        } => execute_commit_review(deps, env, info, proposal_id, commitment),
+        ExecuteMsg::SubmitReview {
+            proposal_id,
+            score,
+            recommendation,
+            report_ipfs_hash,
+            salt,
+        } => execute_submit_review(
+            deps,
+            env,
+            info,
+            proposal_id,
+            score,
+            recommendation,
+            report_ipfs_hash,
+            salt,
         ),
+        ExecuteMsg::AcceptReview {
+            proposal_id,
+            reviewer,
+        } => execute_accept_review(deps, env, info, proposal_id, reviewer),
+        ExecuteMsg::SettleReviewBounty { proposal_id } => {
+            execute_settle_review_bounty(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::DispatchCustomMessages { proposal_id } => {
+            execute_dispatch_custom_messages(deps, env, info, proposal_id)
+        }
+    }
+}
+
+/// 所有者提名新所有者，覆盖之前未接受的提名
+pub fn execute_propose_new_owner(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_owner: String,
+    expiry: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_contract_owner(deps.as_ref(), &info.sender)?;
+
+    let new_owner = deps.api.addr_validate(&new_owner)?;
+    let expires = expiry.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::Expired {});
     }
+
+    PENDING_OWNER.save(
+        deps.storage,
+        &PendingOwner {
+            owner: new_owner.clone(),
+            expires,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "propose_new_owner")
+        .add_attribute("owner", info.sender)
+        .add_attribute("pending_owner", new_owner)
+        .add_attribute("expires", expires.to_string()))
+}
+
+/// 被提名者在提名过期前接受所有权
+pub fn execute_accept_ownership(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    let pending = PENDING_OWNER
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingOwner {})?;
+    if pending.owner != info.sender {
+        return Err(ContractError::NotAuthorized {});
+    }
+    if pending.expires.is_expired(&env.block) {
+        return Err(ContractError::Expired {});
+    }
+
+    let previous_owner = CONTRACT_OWNER.may_load(deps.storage)?;
+    CONTRACT_OWNER.save(deps.storage, &pending.owner)?;
+    PENDING_OWNER.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("method", "accept_ownership")
+        .add_attribute(
+            "previous_owner",
+            previous_owner.map_or_else(|| "none".to_string(), |owner| owner.to_string()),
+        )
+        .add_attribute("owner", pending.owner))
+}
+
+/// 所有者放弃所有权，同时取消未接受的提名
+pub fn execute_renounce_ownership(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_contract_owner(deps.as_ref(), &info.sender)?;
+
+    CONTRACT_OWNER.remove(deps.storage);
+    PENDING_OWNER.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("method", "renounce_ownership")
+        .add_attribute("previous_owner", info.sender))
 }
 
 pub fn execute_cite_paper(
@@ -136,23 +418,39 @@ pub fn execute_cite_paper(
     env: Env,
     info: MessageInfo,
     paper_id: String,
+    citing_paper_id: Option<String>,
 ) -> Result<Response, ContractError> {
     // 检查论文是否存在
     let paper_owner = TOKEN_OWNERS
         .load(deps.storage, &paper_id)
         .map_err(|_| ContractError::TokenNotFound {})?;
 
-    let base_fee = BASE_CITATION_FEE.load(deps.storage)?;
+    // 引用方论文必须存在且由调用者拥有或授权，同一对论文只记录一条边
+    if let Some(citing) = &citing_paper_id {
+        if citing == &paper_id {
+            return Err(ContractError::SelfCitation {});
+        }
+        if !TOKEN_OWNERS.has(deps.storage, citing) {
+            return Err(ContractError::TokenNotFound {});
+        }
+        if !is_approved_or_owner(deps.as_ref(), &env, &info.sender, citing)? {
+            return Err(ContractError::NotAuthorized {});
+        }
+        if CITATION_EDGES.has(deps.storage, (citing, &paper_id)) {
+            return Err(ContractError::DuplicateCitation {
+                citing: citing.clone(),
+                cited: paper_id,
+            });
+        }
+    }
+
+    let fee_config = FEE_CONFIG.load(deps.storage)?;
 
     // 检查付款
-    let payment = info
-        .funds
-        .iter()
-        .find(|coin| coin.denom == "inj")
-        .map(|coin| coin.amount)
-        .unwrap_or_else(Uint128::zero);
-
-    if payment < base_fee {
+    let denom = fee_config.base_citation_fee.denom.clone();
+    let payment = must_pay(&info, &denom)?;
+
+    if payment < fee_config.base_citation_fee.amount {
         return Err(ContractError::InsufficientPayment {});
     }
 
@@ -169,51 +467,86 @@ pub fn execute_cite_paper(
     citations.push(citation);
     CITATIONS.save(deps.storage, &paper_id, &citations)?;
 
+    if let Some(citing) = &citing_paper_id {
+        CITATION_EDGES.save(
+            deps.storage,
+            (citing, &paper_id),
+            &CitationEdge {
+                citing_paper_id: citing.clone(),
+                cited_paper_id: paper_id.clone(),
+                citer: info.sender.clone(),
+                timestamp: env.block.time.seconds(),
+            },
+        )?;
+        CITATION_COUNTS.update(deps.storage, &paper_id, |counts| -> StdResult<_> {
+            let mut counts = counts.unwrap_or_default();
+            counts.cited_by += 1;
+            Ok(counts)
+        })?;
+        CITATION_COUNTS.update(deps.storage, citing, |counts| -> StdResult<_> {
+            let mut counts = counts.unwrap_or_default();
+            counts.references += 1;
+            Ok(counts)
+        })?;
+    }
+
+    // 只有 DAO 批准发表的论文被他人引用才计入声誉
+    // 引用方论文的所有者、调用者与被引论文所有者相同时视为自引
+    let citing_owner = match &citing_paper_id {
+        Some(citing) => TOKEN_OWNERS.load(deps.storage, citing)?,
+        None => info.sender.clone(),
+    };
+    if APPROVED_PAPERS.has(deps.storage, &paper_id)
+        && citing_owner != paper_owner
+        && info.sender != paper_owner
+    {
+        record_reputation(
+            deps.storage,
+            &env,
+            &paper_owner,
+            ReputationEvent::CitationReceived,
+        )?;
+    }
+
     let mut response = Response::new()
         .add_attribute("method", "cite_paper")
         .add_attribute("paper_id", paper_id)
+        .add_attribute("citing_paper_id", citing_paper_id.unwrap_or_default())
         .add_attribute("citer", info.sender.to_string())
         .add_attribute("amount", payment.to_string());
 
-    // 分配费用：95% 给作者，5% 给 DAO
+    // 按费用配置分配：DAO 份额留在合约中计入金库，其余给作者
     if payment > Uint128::zero() {
-        let dao_share = payment * Uint128::new(5) / Uint128::new(100);
+        let dao_share = bps_share(payment, fee_config.citation_dao_share_bps);
         let author_share = payment - dao_share;
 
-        // 发送给作者
-        let author_msg = CosmosMsg::Bank(BankMsg::Send {
-            to_address: paper_owner.to_string(),
-            amount: vec![Coin {
-                denom: "inj".to_string(),
-                amount: author_share,
-            }],
-        });
+        if !author_share.is_zero() {
+            response = response.add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: paper_owner.to_string(),
+                amount: vec![Coin {
+                    denom: denom.clone(),
+                    amount: author_share,
+                }],
+            }));
+        }
 
-        // 发送给 DAO (合约所有者)
-        let contract_owner = CONTRACT_OWNER.load(deps.storage)?;
-        let dao_msg = CosmosMsg::Bank(BankMsg::Send {
-            to_address: contract_owner.to_string(),
-            amount: vec![Coin {
-                denom: "inj".to_string(),
-                amount: dao_share,
-            }],
-        });
+        credit_treasury(deps.storage, &denom, dao_share)?;
 
-        response = response.add_messages(vec![author_msg, dao_msg]);
+        response = response.add_attribute("dao_share", dao_share.to_string());
     }
 
     Ok(response)
 }
 
 pub fn execute_submit_correction(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     original_paper_id: String,
     new_ipfs_hash: String,
 ) -> Result<Response, ContractError> {
     // 检查对原论文的授权
-    if !is_approved_or_owner(deps.as_ref(), &info.sender, &original_paper_id)? {
+    if !is_approved_or_owner(deps.as_ref(), &env, &info.sender, &original_paper_id)? {
         return Err(ContractError::NotAuthorized {});
     }
 
@@ -221,49 +554,32 @@ pub fn execute_submit_correction(
     let original_doi = PAPER_DOIS.load(deps.storage, &original_paper_id)?;
     let original_versions = DATA_VERSIONS.load(deps.storage, &original_paper_id)?;
 
-    // 创建修正版本的 DOI (添加版本号)
-    let correction_doi = format!("{}-v{}", original_doi, original_versions.len() + 1);
-
-    // 获取下一个 token ID
-    let token_id = TOKEN_ID_COUNTER.load(deps.storage)?;
-    let token_id_str = token_id.to_string();
-
-    // 检查 token 是否已存在
-    if TOKEN_OWNERS.has(deps.storage, &token_id_str) {
-        return Err(ContractError::TokenExists {});
-    }
-
-    // 创建 NFT token
-    TOKEN_OWNERS.save(deps.storage, &token_id_str, &info.sender)?;
-
-    // 创建修正版本的数据项
-    let data_item = DataItem {
-        owner: info.sender.clone(),
-        ipfs_hash: new_ipfs_hash.clone(),
-        price: Uint128::zero(),
-        is_public: true,
-        total_earned: Uint128::zero(),
-        created_at: env.block.time.seconds(),
-        last_updated: env.block.time.seconds(),
-        metadata_uri: original_data.metadata_uri,
-        is_frozen: false,
+    // 创建修正版本的 DOI (添加版本号)，跳过已被之前修正占用的版本号
+    let mut version = original_versions.len() + 1;
+    let correction_doi = loop {
+        let candidate = format!("{}-v{}", original_doi, version);
+        if !DOI_INDEX.has(deps.storage, &candidate) {
+            break candidate;
+        }
+        version += 1;
     };
-    DATA_ITEMS.save(deps.storage, &token_id_str, &data_item)?;
 
-    // 创建初始版本
-    let version = DataVersion {
-        ipfs_hash: new_ipfs_hash.clone(),
-        timestamp: env.block.time.seconds(),
-    };
-    DATA_VERSIONS.save(deps.storage, &token_id_str, &vec![version])?;
+    // 创建修正版本的 NFT token
+    let token_id_str = mint_token(
+        deps.branch(),
+        &env,
+        &info.sender,
+        new_ipfs_hash,
+        original_data.metadata_uri,
+        Coin {
+            denom: original_data.denom,
+            amount: Uint128::zero(),
+        },
+        true,
+    )?;
 
     // 保存修正版本的 DOI
-    PAPER_DOIS.save(deps.storage, &token_id_str, &correction_doi)?;
-
-    // 更新计数器
-    TOKEN_ID_COUNTER.save(deps.storage, &(token_id + 1))?;
-    let count = TOKEN_COUNT.load(deps.storage)?;
-    TOKEN_COUNT.save(deps.storage, &(count + 1))?;
+    save_paper_doi(deps.storage, &token_id_str, &correction_doi)?;
 
     Ok(Response::new()
         .add_attribute("method", "submit_correction")
@@ -274,28 +590,9 @@ pub fn execute_submit_correction(
         .add_attribute("correction_type", "paper_correction"))
 }
 
-pub fn execute_set_base_citation_fee(
-    deps: DepsMut,
-    _env: Env,
-    info: MessageInfo,
-    fee: Uint128,
-) -> Result<Response, ContractError> {
-    // 只有合约所有者可以设置费用
-    let contract_owner = CONTRACT_OWNER.load(deps.storage)?;
-    if info.sender != contract_owner {
-        return Err(ContractError::NotAuthorized {});
-    }
-
-    BASE_CITATION_FEE.save(deps.storage, &fee)?;
-
-    Ok(Response::new()
-        .add_attribute("method", "set_base_citation_fee")
-        .add_attribute("new_fee", fee.to_string()))
-}
-
 pub fn execute_request_access(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     token_id: String,
 ) -> Result<Response, ContractError> {
@@ -311,57 +608,73 @@ pub fn execute_request_access(
         .add_attribute("token_id", token_id.clone())
         .add_attribute("requester", info.sender.to_string());
 
-    if !data_item.is_public {
-        // 检查授权
-        let access_level = ACCESS_CONTROLS
-            .may_load(deps.storage, (&token_id, info.sender.as_str()))?
-            .unwrap_or(AccessLevel::None);
-
-        let is_owner = owner == info.sender;
-        let is_approved = TOKEN_APPROVALS
-            .may_load(deps.storage, &token_id)?
-            .map(|approved| approved == info.sender)
-            .unwrap_or(false);
-        let is_operator = OPERATOR_APPROVALS
-            .may_load(deps.storage, (owner.as_str(), info.sender.as_str()))?
-            .unwrap_or(false);
-
-        if !is_owner && !is_approved && !is_operator && access_level == AccessLevel::None {
-            return Err(ContractError::NotAuthorized {});
-        }
+    if data_item.is_public {
+        nonpayable(&info)?;
+        return Ok(response.add_attribute("access", "public"));
+    }
 
-        // 处理付款
-        if !info.funds.is_empty() {
-            let payment = info
-                .funds
-                .iter()
-                .find(|coin| coin.denom == "inj") // 假设使用原生代币
-                .map(|coin| coin.amount)
-                .unwrap_or_else(Uint128::zero);
+    // 检查授权
+    let access_level = ACCESS_CONTROLS
+        .may_load(deps.storage, (&token_id, info.sender.as_str()))?
+        .unwrap_or(AccessLevel::None);
 
-            if payment < data_item.price {
-                return Err(ContractError::InsufficientPayment {});
-            }
+    if access_level != AccessLevel::None
+        || is_approved_or_owner(deps.as_ref(), &env, &info.sender, &token_id)?
+    {
+        nonpayable(&info)?;
+        return Ok(response.add_attribute("access", "existing"));
+    }
 
-            // 发送付款给所有者
-            if payment > Uint128::zero() {
-                let send_msg = CosmosMsg::Bank(BankMsg::Send {
-                    to_address: owner.to_string(),
-                    amount: vec![Coin {
-                        denom: "inj".to_string(),
-                        amount: payment,
-                    }],
-                });
-                response = response.add_message(send_msg);
+    // 已退役的数据不再出售新的访问权限
+    if data_item.is_retired {
+        return Err(ContractError::DataRetired {});
+    }
 
-                // 更新总收入
-                data_item.total_earned += payment;
-                DATA_ITEMS.save(deps.storage, &token_id, &data_item)?;
-            }
-        }
+    // 未授权用户需要支付访问费用购买读取权限，价格为 0 的私有数据不对外出售
+    if data_item.price.is_zero() {
+        return Err(ContractError::NotAuthorized {});
     }
 
-    Ok(response)
+    // 使用数据项设定的计价代币付款，该代币需仍在接受列表中
+    ensure_accepted_denom(deps.as_ref(), &data_item.denom)?;
+    let payment = must_pay(&info, &data_item.denom)?;
+
+    if payment < data_item.price {
+        return Err(ContractError::InsufficientPayment {});
+    }
+
+    // 协议分成计入 DAO 金库，其余发送给所有者
+    let fee_config = FEE_CONFIG.load(deps.storage)?;
+    let protocol_fee = bps_share(payment, fee_config.access_protocol_fee_bps);
+    let owner_share = payment - protocol_fee;
+    credit_treasury(deps.storage, &data_item.denom, protocol_fee)?;
+
+    if !owner_share.is_zero() {
+        response = response.add_message(CosmosMsg::Bank(BankMsg::Send {
+            to_address: owner.to_string(),
+            amount: vec![Coin {
+                denom: data_item.denom.clone(),
+                amount: owner_share,
+            }],
+        }));
+    }
+
+    // 更新所有者总收入
+    data_item.total_earned += owner_share;
+    DATA_ITEMS.save(deps.storage, &token_id, &data_item)?;
+
+    // 授予读取权限
+    ACCESS_CONTROLS.save(
+        deps.storage,
+        (&token_id, info.sender.as_str()),
+        &AccessLevel::Read,
+    )?;
+    AUTHORIZED_USERS.save(deps.storage, (&token_id, &info.sender), &Empty {})?;
+
+    Ok(response
+        .add_attribute("access", "purchased")
+        .add_attribute("amount", payment.to_string())
+        .add_attribute("protocol_fee", protocol_fee.to_string()))
 }
 
 pub fn execute_update_data_item(
@@ -377,7 +690,7 @@ pub fn execute_update_data_item(
         .map_err(|_| ContractError::TokenNotFound {})?;
 
     // 检查授权 - 必须是所有者或被批准的用户
-    if !is_approved_or_owner(deps.as_ref(), &info.sender, &token_id)? {
+    if !is_approved_or_owner(deps.as_ref(), &env, &info.sender, &token_id)? {
         return Err(ContractError::NotAuthorized {});
     }
 
@@ -405,9 +718,56 @@ pub fn execute_update_data_item(
         .add_attribute("new_ipfs_hash", new_ipfs_hash))
 }
 
+pub fn execute_update_data_item_terms(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    price: Option<Uint128>,
+    denom: Option<String>,
+    is_public: Option<bool>,
+) -> Result<Response, ContractError> {
+    let mut data_item = DATA_ITEMS
+        .load(deps.storage, &token_id)
+        .map_err(|_| ContractError::TokenNotFound {})?;
+
+    // 只有所有者可以修改访问条款
+    let owner = TOKEN_OWNERS.load(deps.storage, &token_id)?;
+    if owner != info.sender {
+        return Err(ContractError::NotAuthorized {});
+    }
+
+    // 冻结的数据不能再修改条款
+    if data_item.is_frozen {
+        return Err(ContractError::DataFrozen {});
+    }
+
+    update_listed_denoms(deps.storage, &data_item, false)?;
+    if let Some(price) = price {
+        data_item.price = price;
+    }
+    if let Some(denom) = denom {
+        ensure_accepted_denom(deps.as_ref(), &denom)?;
+        data_item.denom = denom;
+    }
+    update_listed_denoms(deps.storage, &data_item, true)?;
+    if let Some(is_public) = is_public {
+        data_item.is_public = is_public;
+    }
+    data_item.last_updated = env.block.time.seconds();
+    DATA_ITEMS.save(deps.storage, &token_id, &data_item)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_data_item_terms")
+        .add_attribute("token_id", token_id)
+        .add_attribute("price", data_item.price.to_string())
+        .add_attribute("denom", data_item.denom)
+        .add_attribute("is_public", data_item.is_public.to_string()))
+}
+
 pub fn execute_freeze_data(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     token_id: String,
     freeze: bool,
@@ -417,7 +777,7 @@ pub fn execute_freeze_data(
         .map_err(|_| ContractError::TokenNotFound {})?;
 
     // 检查授权
-    if !is_approved_or_owner(deps.as_ref(), &info.sender, &token_id)? {
+    if !is_approved_or_owner(deps.as_ref(), &env, &info.sender, &token_id)? {
         return Err(ContractError::NotAuthorized {});
     }
 
@@ -430,41 +790,84 @@ pub fn execute_freeze_data(
         .add_attribute("frozen", freeze.to_string()))
 }
 
-pub fn execute_grant_access(
+pub fn execute_retire_data(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     token_id: String,
-    grantee: String,
-    level: AccessLevel,
+    retire: bool,
 ) -> Result<Response, ContractError> {
-    // 检查授权 - 必须是所有者
-    if !is_approved_or_owner(deps.as_ref(), &info.sender, &token_id)? {
+    let mut data_item = DATA_ITEMS
+        .load(deps.storage, &token_id)
+        .map_err(|_| ContractError::TokenNotFound {})?;
+
+    // 检查授权
+    if !is_approved_or_owner(deps.as_ref(), &env, &info.sender, &token_id)? {
         return Err(ContractError::NotAuthorized {});
     }
 
-    let grantee_addr = deps.api.addr_validate(&grantee)?;
-
-    // 设置访问级别
-    ACCESS_CONTROLS.save(deps.storage, (&token_id, &grantee), &level)?;
+    data_item.is_retired = retire;
+    data_item.last_updated = env.block.time.seconds();
+    DATA_ITEMS.save(deps.storage, &token_id, &data_item)?;
 
-    // 更新授权用户列表
-    if !matches!(level, AccessLevel::None) {
-        let mut authorized = AUTHORIZED_USERS
-            .may_load(deps.storage, &token_id)?
-            .unwrap_or_default();
+    Ok(Response::new()
+        .add_attribute("method", "retire_data")
+        .add_attribute("token_id", token_id)
+        .add_attribute("retired", retire.to_string()))
+}
 
-        if !authorized.contains(&grantee_addr) {
-            authorized.push(grantee_addr.clone());
-            AUTHORIZED_USERS.save(deps.storage, &token_id, &authorized)?;
-        }
+pub fn execute_burn(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let data_item = DATA_ITEMS
+        .load(deps.storage, &token_id)
+        .map_err(|_| ContractError::TokenNotFound {})?;
+
+    // 检查授权
+    if !is_approved_or_owner(deps.as_ref(), &env, &info.sender, &token_id)? {
+        return Err(ContractError::NotAuthorized {});
+    }
+
+    // 已冻结或已被引用的数据只能通过 DAO 提案销毁
+    if data_item.is_frozen || has_citations(deps.as_ref(), &token_id)? {
+        return Err(ContractError::BurnRequiresDaoApproval {});
+    }
+
+    burn_token(deps.storage, &token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "burn")
+        .add_attribute("token_id", token_id)
+        .add_attribute("owner", data_item.owner))
+}
+
+pub fn execute_grant_access(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    grantee: String,
+    level: AccessLevel,
+) -> Result<Response, ContractError> {
+    // 检查授权 - 必须是所有者
+    if !is_approved_or_owner(deps.as_ref(), &env, &info.sender, &token_id)? {
+        return Err(ContractError::NotAuthorized {});
+    }
+
+    let grantee_addr = deps.api.addr_validate(&grantee)?;
+
+    // 设置访问级别
+    ACCESS_CONTROLS.save(deps.storage, (&token_id, &grantee), &level)?;
+
+    // 更新授权用户列表
+    if !matches!(level, AccessLevel::None) {
+        AUTHORIZED_USERS.save(deps.storage, (&token_id, &grantee_addr), &Empty {})?;
     } else {
         // 如果设置为 None，从授权列表中移除
-        let mut authorized = AUTHORIZED_USERS
-            .may_load(deps.storage, &token_id)?
-            .unwrap_or_default();
-        authorized.retain(|addr| addr != grantee_addr);
-        AUTHORIZED_USERS.save(deps.storage, &token_id, &authorized)?;
+        AUTHORIZED_USERS.remove(deps.storage, (&token_id, &grantee_addr));
     }
 
     Ok(Response::new()
@@ -476,78 +879,163 @@ pub fn execute_grant_access(
 
 pub fn execute_transfer_nft(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     recipient: String,
     token_id: String,
 ) -> Result<Response, ContractError> {
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+    let owner = transfer_token(deps, &env, &info.sender, &recipient_addr, &token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "transfer_nft")
+        .add_attribute("token_id", token_id)
+        .add_attribute("from", owner)
+        .add_attribute("to", recipient_addr))
+}
+
+/// 将 NFT 转移给合约，并调用接收合约的 `ReceiveNft` 回调
+pub fn execute_send_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    contract: String,
+    token_id: String,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let contract_addr = deps.api.addr_validate(&contract)?;
+    let owner = transfer_token(deps, &env, &info.sender, &contract_addr, &token_id)?;
+
+    // 接收合约执行失败时整个转移回滚
+    let receive_msg = Cw721ReceiveMsg {
+        sender: info.sender.to_string(),
+        token_id: token_id.clone(),
+        msg,
+    }
+    .into_cosmos_msg(contract_addr.clone())?;
+
+    Ok(Response::new()
+        .add_message(receive_msg)
+        .add_attribute("method", "send_nft")
+        .add_attribute("token_id", token_id)
+        .add_attribute("from", owner)
+        .add_attribute("to", contract_addr))
+}
+
+/// 转移 token 所有权并清除其批准，返回原所有者
+fn transfer_token(
+    deps: DepsMut,
+    env: &Env,
+    sender: &Addr,
+    recipient: &Addr,
+    token_id: &str,
+) -> Result<Addr, ContractError> {
     let owner = TOKEN_OWNERS
-        .load(deps.storage, &token_id)
+        .load(deps.storage, token_id)
         .map_err(|_| ContractError::TokenNotFound {})?;
 
     // 检查授权
-    if !is_approved_or_owner(deps.as_ref(), &info.sender, &token_id)? {
+    if !is_approved_or_owner(deps.as_ref(), env, sender, token_id)? {
         return Err(ContractError::NotAuthorized {});
     }
 
-    let recipient_addr = deps.api.addr_validate(&recipient)?;
-
     // 转移所有权
-    TOKEN_OWNERS.save(deps.storage, &token_id, &recipient_addr)?;
+    TOKEN_OWNERS.save(deps.storage, token_id, recipient)?;
 
     // 更新数据项中的所有者
-    let mut data_item = DATA_ITEMS.load(deps.storage, &token_id)?;
-    data_item.owner = recipient_addr.clone();
-    DATA_ITEMS.save(deps.storage, &token_id, &data_item)?;
+    let mut data_item = DATA_ITEMS.load(deps.storage, token_id)?;
+    data_item.owner = recipient.clone();
+    DATA_ITEMS.save(deps.storage, token_id, &data_item)?;
 
     // 清除批准
-    TOKEN_APPROVALS.remove(deps.storage, &token_id);
+    clear_token_approvals(deps.storage, token_id)?;
 
-    Ok(Response::new()
-        .add_attribute("method", "transfer_nft")
-        .add_attribute("token_id", token_id)
-        .add_attribute("from", owner)
-        .add_attribute("to", recipient_addr))
+    Ok(owner)
 }
 
 pub fn execute_approve(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     spender: String,
     token_id: String,
+    expires: Option<Expiration>,
 ) -> Result<Response, ContractError> {
     let owner = TOKEN_OWNERS
         .load(deps.storage, &token_id)
         .map_err(|_| ContractError::TokenNotFound {})?;
 
-    // 只有所有者可以批准
-    if owner != info.sender {
+    // 所有者或其操作员可以批准
+    if !is_owner_or_operator(deps.as_ref(), &env, &info.sender, &owner)? {
         return Err(ContractError::NotAuthorized {});
     }
 
+    // 不能设置已经过期的批准
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::Expired {});
+    }
+
     let spender_addr = deps.api.addr_validate(&spender)?;
-    TOKEN_APPROVALS.save(deps.storage, &token_id, &spender_addr)?;
+    TOKEN_APPROVALS.save(deps.storage, (&token_id, spender_addr.as_str()), &expires)?;
 
     Ok(Response::new()
         .add_attribute("method", "approve")
         .add_attribute("token_id", token_id)
+        .add_attribute("spender", spender_addr)
+        .add_attribute("expires", expires.to_string()))
+}
+
+pub fn execute_revoke(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let owner = TOKEN_OWNERS
+        .load(deps.storage, &token_id)
+        .map_err(|_| ContractError::TokenNotFound {})?;
+
+    // 所有者或其操作员可以撤销批准
+    if !is_owner_or_operator(deps.as_ref(), &env, &info.sender, &owner)? {
+        return Err(ContractError::NotAuthorized {});
+    }
+
+    let spender_addr = deps.api.addr_validate(&spender)?;
+    TOKEN_APPROVALS.remove(deps.storage, (&token_id, spender_addr.as_str()));
+
+    Ok(Response::new()
+        .add_attribute("method", "revoke")
+        .add_attribute("token_id", token_id)
         .add_attribute("spender", spender_addr))
 }
 
 pub fn execute_approve_all(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     operator: String,
+    expires: Option<Expiration>,
 ) -> Result<Response, ContractError> {
+    // 不能设置已经过期的批准
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::Expired {});
+    }
+
     let operator_addr = deps.api.addr_validate(&operator)?;
-    OPERATOR_APPROVALS.save(deps.storage, (info.sender.as_str(), &operator), &true)?;
+    OPERATOR_APPROVALS.save(
+        deps.storage,
+        (info.sender.as_str(), operator_addr.as_str()),
+        &expires,
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "approve_all")
         .add_attribute("owner", info.sender)
-        .add_attribute("operator", operator_addr))
+        .add_attribute("operator", operator_addr)
+        .add_attribute("expires", expires.to_string()))
 }
 
 pub fn execute_revoke_all(
@@ -557,7 +1045,7 @@ pub fn execute_revoke_all(
     operator: String,
 ) -> Result<Response, ContractError> {
     let operator_addr = deps.api.addr_validate(&operator)?;
-    OPERATOR_APPROVALS.remove(deps.storage, (info.sender.as_str(), &operator));
+    OPERATOR_APPROVALS.remove(deps.storage, (info.sender.as_str(), operator_addr.as_str()));
 
     Ok(Response::new()
         .add_attribute("method", "revoke_all")
@@ -565,6 +1053,56 @@ pub fn execute_revoke_all(
         .add_attribute("operator", operator_addr))
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn execute_create_data_item(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ipfs_hash: String,
+    metadata_uri: String,
+    price: Uint128,
+    denom: Option<String>,
+    is_public: bool,
+) -> Result<Response, ContractError> {
+    if ipfs_hash.trim().is_empty() {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "IPFS hash cannot be empty",
+        )));
+    }
+
+    // 未指定计价代币时使用引用费用的计价单位
+    let denom = match denom {
+        Some(denom) => {
+            ensure_accepted_denom(deps.as_ref(), &denom)?;
+            denom
+        }
+        None => citation_denom(deps.as_ref())?,
+    };
+    let price = Coin {
+        denom,
+        amount: price,
+    };
+
+    let token_id = mint_token(
+        deps,
+        &env,
+        &info.sender,
+        ipfs_hash.clone(),
+        metadata_uri,
+        price.clone(),
+        is_public,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "create_data_item")
+        .add_attribute("token_id", token_id)
+        .add_attribute("owner", info.sender)
+        .add_attribute("ipfs_hash", ipfs_hash)
+        .add_attribute("price", price.to_string())
+        .add_attribute("is_public", is_public.to_string()))
+}
+
+/// 直接铸造的论文不经 DAO 批准，不计入声誉
 pub fn execute_create_paper_item(
     deps: DepsMut,
     env: Env,
@@ -573,6 +1111,87 @@ pub fn execute_create_paper_item(
     doi: String,
     metadata_uri: String,
 ) -> Result<Response, ContractError> {
+    let (token_id_str, doi) = create_paper(
+        deps,
+        &env,
+        &info.sender,
+        ipfs_hash.clone(),
+        &doi,
+        metadata_uri,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "create_paper_item")
+        .add_attribute("token_id", token_id_str)
+        .add_attribute("owner", info.sender)
+        .add_attribute("ipfs_hash", ipfs_hash)
+        .add_attribute("paper_doi", doi)
+        .add_attribute("paper_type", "academic_paper"))
+}
+
+/// 铸造论文并登记 DOI，返回 token ID 和规范化后的 DOI
+fn create_paper(
+    mut deps: DepsMut,
+    env: &Env,
+    owner: &Addr,
+    ipfs_hash: String,
+    doi: &str,
+    metadata_uri: String,
+) -> Result<(String, String), ContractError> {
+    // DOI 必须唯一
+    let doi = ensure_doi_available(deps.as_ref(), doi)?;
+
+    // 创建 NFT token (论文默认公开，价格为 0)
+    let denom = citation_denom(deps.as_ref())?;
+    let token_id = mint_token(
+        deps.branch(),
+        env,
+        owner,
+        ipfs_hash,
+        metadata_uri,
+        Coin {
+            denom,
+            amount: Uint128::zero(),
+        },
+        true,
+    )?;
+
+    // 保存 DOI
+    save_paper_doi(deps.storage, &token_id, &doi)?;
+    Ok((token_id, doi))
+}
+
+/// 规范化 DOI 并确认其尚未被登记，返回规范化后的 DOI
+fn ensure_doi_available(deps: Deps, doi: &str) -> Result<String, ContractError> {
+    let doi = normalize_doi(doi)?;
+    if DOI_INDEX.has(deps.storage, &doi) {
+        return Err(ContractError::DuplicateDoi { doi });
+    }
+    Ok(doi)
+}
+
+/// 保存论文 DOI 及其反向索引
+fn save_paper_doi(
+    storage: &mut dyn cosmwasm_std::Storage,
+    token_id: &str,
+    doi: &str,
+) -> StdResult<()> {
+    PAPER_DOIS.save(storage, token_id, &doi.to_string())?;
+    DOI_INDEX.save(storage, doi, &token_id.to_string())
+}
+
+/// 铸造新的数据 NFT，写入所有权、数据项和初始版本并更新计数器
+/// `price` 同时携带访问价格和计价代币
+/// 返回新 token 的 ID
+fn mint_token(
+    deps: DepsMut,
+    env: &Env,
+    owner: &Addr,
+    ipfs_hash: String,
+    metadata_uri: String,
+    price: Coin,
+    is_public: bool,
+) -> Result<String, ContractError> {
     // 获取下一个 token ID
     let token_id = TOKEN_ID_COUNTER.load(deps.storage)?;
     let token_id_str = token_id.to_string();
@@ -583,76 +1202,167 @@ pub fn execute_create_paper_item(
     }
 
     // 创建 NFT token
-    TOKEN_OWNERS.save(deps.storage, &token_id_str, &info.sender)?;
+    TOKEN_OWNERS.save(deps.storage, &token_id_str, owner)?;
 
-    // 创建数据项 (论文默认公开，价格为 0)
+    // 创建数据项
     let data_item = DataItem {
-        owner: info.sender.clone(),
+        owner: owner.clone(),
         ipfs_hash: ipfs_hash.clone(),
-        price: Uint128::zero(),
-        is_public: true, // 论文默认公开
+        price: price.amount,
+        denom: price.denom,
+        is_public,
         total_earned: Uint128::zero(),
         created_at: env.block.time.seconds(),
         last_updated: env.block.time.seconds(),
-        metadata_uri: metadata_uri.clone(),
+        metadata_uri,
         is_frozen: false,
+        is_retired: false,
     };
     DATA_ITEMS.save(deps.storage, &token_id_str, &data_item)?;
+    update_listed_denoms(deps.storage, &data_item, true)?;
 
     // 创建初始版本
     let version = DataVersion {
-        ipfs_hash: ipfs_hash.clone(),
+        ipfs_hash,
         timestamp: env.block.time.seconds(),
     };
     DATA_VERSIONS.save(deps.storage, &token_id_str, &vec![version])?;
 
-    // 保存 DOI
-    PAPER_DOIS.save(deps.storage, &token_id_str, &doi)?;
-
     // 更新计数器
     TOKEN_ID_COUNTER.save(deps.storage, &(token_id + 1))?;
     let count = TOKEN_COUNT.load(deps.storage)?;
     TOKEN_COUNT.save(deps.storage, &(count + 1))?;
 
-    Ok(Response::new()
-        .add_attribute("method", "create_paper_item")
-        .add_attribute("token_id", token_id_str)
-        .add_attribute("owner", info.sender)
-        .add_attribute("ipfs_hash", ipfs_hash)
-        .add_attribute("paper_doi", doi)
-        .add_attribute("paper_type", "academic_paper"))
+    Ok(token_id_str)
 }
 
 // 辅助函数
-fn is_approved_or_owner(deps: Deps, spender: &Addr, token_id: &str) -> StdResult<bool> {
+fn is_approved_or_owner(deps: Deps, env: &Env, spender: &Addr, token_id: &str) -> StdResult<bool> {
     let owner = TOKEN_OWNERS.load(deps.storage, token_id)?;
 
-    if owner == *spender {
+    // 所有者或操作员
+    if is_owner_or_operator(deps, env, spender, &owner)? {
         return Ok(true);
     }
 
     // 检查单个 token 批准
-    if let Ok(approved) = TOKEN_APPROVALS.load(deps.storage, token_id) {
-        if approved == *spender {
+    if let Some(expires) = TOKEN_APPROVALS.may_load(deps.storage, (token_id, spender.as_str()))? {
+        if !expires.is_expired(&env.block) {
             return Ok(true);
         }
     }
 
-    // 检查操作员批准
-    if let Ok(is_operator) =
-        OPERATOR_APPROVALS.load(deps.storage, (owner.as_str(), spender.as_str()))
-    {
-        if is_operator {
-            return Ok(true);
-        }
+    Ok(false)
+}
+
+/// 检查地址是否为所有者本人或其未过期的操作员
+fn is_owner_or_operator(deps: Deps, env: &Env, spender: &Addr, owner: &Addr) -> StdResult<bool> {
+    if owner == spender {
+        return Ok(true);
     }
 
-    Ok(false)
+    let operator = OPERATOR_APPROVALS.may_load(deps.storage, (owner.as_str(), spender.as_str()))?;
+    Ok(operator.is_some_and(|expires| !expires.is_expired(&env.block)))
+}
+
+/// 清除 token 的所有单独批准
+fn clear_token_approvals(storage: &mut dyn cosmwasm_std::Storage, token_id: &str) -> StdResult<()> {
+    let spenders: Vec<String> = TOKEN_APPROVALS
+        .prefix(token_id)
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for spender in spenders {
+        TOKEN_APPROVALS.remove(storage, (token_id, &spender));
+    }
+    Ok(())
+}
+
+/// 检查 token 是否已被引用
+fn has_citations(deps: Deps, token_id: &str) -> StdResult<bool> {
+    Ok(CITATIONS
+        .may_load(deps.storage, token_id)?
+        .is_some_and(|citations| !citations.is_empty()))
+}
+
+/// 删除论文两个方向的引用边，并同步减少另一端论文的引用计数
+fn remove_citation_edges(storage: &mut dyn cosmwasm_std::Storage, token_id: &str) -> StdResult<()> {
+    let cited: Vec<String> = CITATION_EDGES
+        .prefix(token_id)
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for cited in cited {
+        CITATION_EDGES.remove(storage, (token_id, &cited))?;
+        CITATION_COUNTS.update(storage, &cited, |counts| -> StdResult<_> {
+            let mut counts = counts.unwrap_or_default();
+            counts.cited_by = counts.cited_by.saturating_sub(1);
+            Ok(counts)
+        })?;
+    }
+
+    let citing: Vec<String> = CITATION_EDGES
+        .idx
+        .cited
+        .prefix(token_id.to_string())
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|key| key.map(|(citing, _)| citing))
+        .collect::<StdResult<_>>()?;
+    for citing in citing {
+        CITATION_EDGES.remove(storage, (&citing, token_id))?;
+        CITATION_COUNTS.update(storage, &citing, |counts| -> StdResult<_> {
+            let mut counts = counts.unwrap_or_default();
+            counts.references = counts.references.saturating_sub(1);
+            Ok(counts)
+        })?;
+    }
+
+    CITATION_COUNTS.remove(storage, token_id);
+    Ok(())
+}
+
+/// 删除 token 及其所有相关存储，并减少 token 总数
+fn burn_token(storage: &mut dyn cosmwasm_std::Storage, token_id: &str) -> StdResult<()> {
+    TOKEN_OWNERS.remove(storage, token_id)?;
+    if let Some(data_item) = DATA_ITEMS.may_load(storage, token_id)? {
+        update_listed_denoms(storage, &data_item, false)?;
+    }
+    DATA_ITEMS.remove(storage, token_id);
+    DATA_VERSIONS.remove(storage, token_id);
+    CITATIONS.remove(storage, token_id);
+    APPROVED_PAPERS.remove(storage, token_id);
+    remove_citation_edges(storage, token_id)?;
+    if let Some(doi) = PAPER_DOIS.may_load(storage, token_id)? {
+        DOI_INDEX.remove(storage, &doi);
+        PAPER_DOIS.remove(storage, token_id);
+    }
+    clear_token_approvals(storage, token_id)?;
+
+    let grantees: Vec<String> = ACCESS_CONTROLS
+        .prefix(token_id)
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for grantee in grantees {
+        ACCESS_CONTROLS.remove(storage, (token_id, &grantee));
+    }
+
+    let users: Vec<Addr> = AUTHORIZED_USERS
+        .prefix(token_id)
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for user in users {
+        AUTHORIZED_USERS.remove(storage, (token_id, &user));
+    }
+
+    TOKEN_COUNT.update(storage, |count| -> StdResult<_> {
+        Ok(count.saturating_sub(1))
+    })?;
+    Ok(())
 }
 
 /// 提交文章发布提案
+/// 可同时附带评审赏金，赏金从附带的资金中扣出，其余资金按提案押金处理
+#[allow(clippy::too_many_arguments)]
 pub fn execute_submit_article_proposal(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     ipfs_hash: String,
@@ -660,8 +1370,87 @@ pub fn execute_submit_article_proposal(
     metadata_uri: String,
     title: String,
     description: String,
+    review_bounty: Option<Coin>,
 ) -> Result<Response, ContractError> {
+    if title.trim().is_empty() {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Title cannot be empty",
+        )));
+    }
+
     // 验证文章信息的完整性
+    let doi = validate_article_data(deps.as_ref(), &ipfs_hash, &doi, &metadata_uri)?;
+
+    // 评审赏金只在开启同行评审时可用，名额按当前要求的评审数量计算
+    let bounty = match review_bounty {
+        Some(amount) => {
+            let review_config = DAO_CONFIG
+                .load(deps.storage)?
+                .review_config
+                .ok_or(ContractError::ReviewBountyUnavailable {})?;
+            if amount.amount.is_zero() {
+                return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+                    "Review bounty must be greater than zero",
+                )));
+            }
+            ensure_accepted_denom(deps.as_ref(), &amount.denom)?;
+            Some(ReviewBounty {
+                amount,
+                reviewer_slots: review_config.required_reviews,
+                deadline: env.block.time.seconds() + review_config.review_period,
+                status: ReviewBountyStatus::Escrowed,
+            })
+        }
+        None => None,
+    };
+    let proposal_info = match &bounty {
+        Some(bounty) => take_payment(&info, &bounty.amount)?,
+        None => info.clone(),
+    };
+
+    // 创建文章发布提案
+    let mut proposal = create_proposal(
+        deps.branch(),
+        &env,
+        &proposal_info,
+        ProposalType::ArticlePublication,
+        title,
+        description,
+        ExecutionData::ArticlePublication {
+            ipfs_hash: ipfs_hash.clone(),
+            doi: doi.clone(),
+            metadata_uri,
+        },
+    )?;
+    let proposal_id = proposal.id;
+    let voting_end = proposal.voting_end;
+    let bounty_amount = bounty
+        .as_ref()
+        .map_or_else(String::new, |bounty| bounty.amount.to_string());
+    if bounty.is_some() {
+        proposal.review_bounty = bounty;
+        PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    }
+
+    // 返回唯一的提案 ID
+    Ok(Response::new()
+        .add_attribute("method", "submit_article_proposal")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("proposer", info.sender.to_string())
+        .add_attribute("article_ipfs_hash", ipfs_hash)
+        .add_attribute("article_doi", doi)
+        .add_attribute("voting_end", voting_end.to_string())
+        .add_attribute("review_bounty", bounty_amount)
+        .add_attribute("proposal_type", "article_publication"))
+}
+
+/// 验证文章发布提案的数据，返回规范化后的 DOI
+fn validate_article_data(
+    deps: Deps,
+    ipfs_hash: &str,
+    doi: &str,
+    metadata_uri: &str,
+) -> Result<String, ContractError> {
     if ipfs_hash.trim().is_empty() {
         return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
             "IPFS hash cannot be empty",
@@ -680,12 +1469,6 @@ pub fn execute_submit_article_proposal(
         )));
     }
 
-    if title.trim().is_empty() {
-        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
-            "Title cannot be empty",
-        )));
-    }
-
     // 验证 IPFS 哈希格式（基本检查）
     if !ipfs_hash.starts_with("Qm") && !ipfs_hash.starts_with("bafy") {
         return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
@@ -693,144 +1476,107 @@ pub fn execute_submit_article_proposal(
         )));
     }
 
-    // 验证 DOI 格式（基本检查）
-    if !doi.contains('/') {
-        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
-            "Invalid DOI format",
-        )));
-    }
-
-    // 获取 DAO 配置
-    let dao_config = DAO_CONFIG.load(deps.storage)?;
-
-    // 获取下一个提案 ID
-    let proposal_id = PROPOSAL_COUNTER.load(deps.storage)?;
+    // 验证 DOI 格式并确认尚未被登记
+    ensure_doi_available(deps, doi)
+}
 
-    // 设置提案的投票截止时间
-    let voting_end = env.block.time.seconds() + dao_config.voting_period;
-
-    // 创建文章发布提案
-    let proposal = Proposal {
-        id: proposal_id,
-        proposer: info.sender.clone(),
-        proposal_type: ProposalType::ArticlePublication,
-        title,
-        description,
-        created_at: env.block.time.seconds(),
-        voting_end,
-        status: ProposalStatus::Active,
-        execution_data: Some(ExecutionData::ArticlePublication {
-            ipfs_hash: ipfs_hash.clone(),
-            doi: doi.clone(),
-            metadata_uri: metadata_uri.clone(),
-        }),
-    };
-
-    // 保存提案
-    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
-
-    // 初始化投票统计
-    let total_members = count_dao_members(deps.as_ref())?;
-    let vote_count = VoteCount {
-        yes: 0,
-        no: 0,
-        abstain: 0,
-        total_eligible: total_members,
-    };
-    VOTE_COUNTS.save(deps.storage, proposal_id, &vote_count)?;
-
-    // 更新提案计数器
-    PROPOSAL_COUNTER.save(deps.storage, &(proposal_id + 1))?;
-
-    // 返回唯一的提案 ID
-    Ok(Response::new()
-        .add_attribute("method", "submit_article_proposal")
-        .add_attribute("proposal_id", proposal_id.to_string())
-        .add_attribute("proposer", info.sender.to_string())
-        .add_attribute("article_ipfs_hash", ipfs_hash)
-        .add_attribute("article_doi", doi)
-        .add_attribute("voting_end", voting_end.to_string())
-        .add_attribute("proposal_type", "article_publication"))
-}
-
-/// 提交成员管理提案
-pub fn execute_submit_member_proposal(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    member_address: String,
-    action: MemberAction,
-    title: String,
-    description: String,
-) -> Result<Response, ContractError> {
-    // 验证提案者是 DAO 成员
-    ensure_dao_member(deps.as_ref(), &info.sender)?;
-
-    // 验证目标地址
-    let target_addr = deps.api.addr_validate(&member_address)?;
+/// 验证成员变更，返回目标地址和添加成员时的权重
+fn validate_member_change(
+    deps: Deps,
+    member_address: &str,
+    action: &MemberAction,
+    weight: Option<u64>,
+) -> Result<(Addr, Option<u64>), ContractError> {
+    // 验证目标地址
+    let target_addr = deps.api.addr_validate(member_address)?;
 
     // 根据操作类型进行验证
-    match action {
+    let weight = match action {
         MemberAction::Add => {
             // 检查成员是否已存在
-            if is_dao_member(deps.as_ref(), &target_addr)? {
+            if is_dao_member(deps, &target_addr)? {
                 return Err(ContractError::MemberAlreadyExists {});
             }
+
+            let weight = weight.unwrap_or(1);
+            validate_member_weight(weight)?;
+            Some(weight)
         }
         MemberAction::Remove => {
+            // 移除成员不需要指定权重
+            if weight.is_some() {
+                return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+                    "Weight is only allowed when adding a member",
+                )));
+            }
+
             // 检查成员是否存在
-            if !is_dao_member(deps.as_ref(), &target_addr)? {
+            if !is_dao_member(deps, &target_addr)? {
                 return Err(ContractError::MemberDoesNotExist {});
             }
 
             // 检查是否为最后一个成员
-            let member_count = count_dao_members(deps.as_ref())?;
+            let member_count = count_dao_members(deps)?;
             if member_count <= 1 {
                 return Err(ContractError::CannotRemoveLastMember {});
             }
+            None
         }
+    };
+    Ok((target_addr, weight))
+}
+
+/// 验证成员权重调整，目标必须是现有成员
+fn validate_member_weight_update(
+    deps: Deps,
+    member_address: &str,
+    weight: u64,
+) -> Result<Addr, ContractError> {
+    let target_addr = deps.api.addr_validate(member_address)?;
+    if !is_dao_member(deps, &target_addr)? {
+        return Err(ContractError::MemberDoesNotExist {});
     }
+    validate_member_weight(weight)?;
+    Ok(target_addr)
+}
 
-    // 获取 DAO 配置
-    let dao_config = DAO_CONFIG.load(deps.storage)?;
+/// 提交成员管理提案
+#[allow(clippy::too_many_arguments)]
+pub fn execute_submit_member_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    member_address: String,
+    action: MemberAction,
+    weight: Option<u64>,
+    title: String,
+    description: String,
+) -> Result<Response, ContractError> {
+    // 验证提案者是 DAO 成员
+    ensure_dao_member(deps.as_ref(), &info.sender)?;
 
-    // 获取下一个提案 ID
-    let proposal_id = PROPOSAL_COUNTER.load(deps.storage)?;
+    let (target_addr, weight) =
+        validate_member_change(deps.as_ref(), &member_address, &action, weight)?;
 
     // 创建提案
-    let proposal = Proposal {
-        id: proposal_id,
-        proposer: info.sender.clone(),
-        proposal_type: match action {
-            MemberAction::Add => ProposalType::AddMember,
-            MemberAction::Remove => ProposalType::RemoveMember,
-        },
+    let proposal_type = match action {
+        MemberAction::Add => ProposalType::AddMember,
+        MemberAction::Remove => ProposalType::RemoveMember,
+    };
+    let proposal = create_proposal(
+        deps,
+        &env,
+        &info,
+        proposal_type,
         title,
         description,
-        created_at: env.block.time.seconds(),
-        voting_end: env.block.time.seconds() + dao_config.voting_period,
-        status: ProposalStatus::Active,
-        execution_data: Some(ExecutionData::MemberChange {
+        ExecutionData::MemberChange {
             member_address: target_addr.to_string(),
             action: action.clone(),
-        }),
-    };
-
-    // 保存提案
-    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
-
-    // 初始化投票统计
-    let total_members = count_dao_members(deps.as_ref())?;
-    let vote_count = VoteCount {
-        yes: 0,
-        no: 0,
-        abstain: 0,
-        total_eligible: total_members,
-    };
-    VOTE_COUNTS.save(deps.storage, proposal_id, &vote_count)?;
-
-    // 更新提案计数器
-    PROPOSAL_COUNTER.save(deps.storage, &(proposal_id + 1))?;
+            weight,
+        },
+    )?;
+    let proposal_id = proposal.id;
 
     Ok(Response::new()
         .add_attribute("method", "submit_member_proposal")
@@ -841,6 +1587,157 @@ pub fn execute_submit_member_proposal(
         .add_attribute("voting_end", proposal.voting_end.to_string()))
 }
 
+/// 提交调整成员投票权重的提案
+pub fn execute_submit_member_weight_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    member_address: String,
+    weight: u64,
+    title: String,
+    description: String,
+) -> Result<Response, ContractError> {
+    // 验证提案者是 DAO 成员
+    ensure_dao_member(deps.as_ref(), &info.sender)?;
+
+    // 目标必须是现有成员
+    let target_addr = validate_member_weight_update(deps.as_ref(), &member_address, weight)?;
+
+    let proposal = create_proposal(
+        deps,
+        &env,
+        &info,
+        ProposalType::UpdateMemberWeight,
+        title,
+        description,
+        ExecutionData::MemberWeightUpdate {
+            member_address: target_addr.to_string(),
+            weight,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "submit_member_weight_proposal")
+        .add_attribute("proposal_id", proposal.id.to_string())
+        .add_attribute("proposer", info.sender.to_string())
+        .add_attribute("target_member", target_addr.to_string())
+        .add_attribute("weight", weight.to_string())
+        .add_attribute("voting_end", proposal.voting_end.to_string()))
+}
+
+/// 提交销毁提案
+/// 已冻结或已被引用的数据需经 DAO 批准后才能销毁
+pub fn execute_submit_burn_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    title: String,
+    description: String,
+) -> Result<Response, ContractError> {
+    let owner = TOKEN_OWNERS
+        .load(deps.storage, &token_id)
+        .map_err(|_| ContractError::TokenNotFound {})?;
+
+    // 只有所有者或被批准者可以提交销毁提案
+    if !is_approved_or_owner(deps.as_ref(), &env, &info.sender, &token_id)? {
+        return Err(ContractError::NotAuthorized {});
+    }
+
+    if title.trim().is_empty() {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Title cannot be empty",
+        )));
+    }
+
+    let proposal = create_proposal(
+        deps,
+        &env,
+        &info,
+        ProposalType::BurnToken,
+        title,
+        description,
+        ExecutionData::BurnToken {
+            token_id: token_id.clone(),
+            owner,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "submit_burn_proposal")
+        .add_attribute("proposal_id", proposal.id.to_string())
+        .add_attribute("proposer", info.sender.to_string())
+        .add_attribute("token_id", token_id)
+        .add_attribute("voting_end", proposal.voting_end.to_string())
+        .add_attribute("proposal_type", "burn_token"))
+}
+
+/// 验证自定义提案的消息列表
+/// 合约余额中包含押金和评审赏金等托管资金，自定义消息不能动用合约资金：
+/// 只允许不附带资金的 Wasm 消息，资金支出走金库支出提案以经过 TREASURY 记账
+fn validate_custom_msgs(msgs: &[CosmosMsg]) -> Result<(), ContractError> {
+    if msgs.is_empty() {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Custom proposal must contain at least one message",
+        )));
+    }
+    for msg in msgs {
+        let moves_funds = match msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { funds, .. })
+            | CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => !funds.is_empty(),
+            CosmosMsg::Wasm(WasmMsg::Migrate { .. })
+            | CosmosMsg::Wasm(WasmMsg::UpdateAdmin { .. })
+            | CosmosMsg::Wasm(WasmMsg::ClearAdmin { .. }) => false,
+            // 银行转账以及其他可能转移合约资金的消息一律拒绝
+            _ => true,
+        };
+        if moves_funds {
+            return Err(ContractError::CustomMsgMovesFunds {});
+        }
+    }
+    Ok(())
+}
+
+/// 提交执行 CosmosMsg 的提案
+/// 消息由合约在提案执行时发出，不能附带合约持有的资金
+pub fn execute_submit_custom_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    msgs: Vec<CosmosMsg>,
+) -> Result<Response, ContractError> {
+    // 验证提案者是 DAO 成员
+    ensure_dao_member(deps.as_ref(), &info.sender)?;
+
+    if title.trim().is_empty() {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Title cannot be empty",
+        )));
+    }
+    validate_custom_msgs(&msgs)?;
+    let msg_count = msgs.len();
+
+    let proposal = create_proposal(
+        deps,
+        &env,
+        &info,
+        ProposalType::Custom,
+        title,
+        description,
+        ExecutionData::Custom { msgs },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "submit_custom_proposal")
+        .add_attribute("proposal_id", proposal.id.to_string())
+        .add_attribute("proposer", info.sender.to_string())
+        .add_attribute("msg_count", msg_count.to_string())
+        .add_attribute("voting_end", proposal.voting_end.to_string())
+        .add_attribute("proposal_type", "custom"))
+}
+
 /// 执行提案
 pub fn execute_proposal(
     mut deps: DepsMut,
@@ -866,16 +1763,19 @@ pub fn execute_proposal(
             ExecutionData::MemberChange {
                 member_address,
                 action,
+                weight,
             } => {
                 let target_addr = deps.api.addr_validate(member_address)?;
 
                 match action {
                     MemberAction::Add => {
-                        // 添加成员
-                        DAO_MEMBERS.save(deps.storage, target_addr.as_str(), &true)?;
+                        // 添加成员，旧提案未指定权重时为 1
+                        let weight = weight.unwrap_or(1);
+                        DAO_MEMBERS.save(deps.storage, target_addr.as_str(), &weight)?;
                         response = response
                             .add_attribute("action", "member_added")
-                            .add_attribute("new_member", target_addr.to_string());
+                            .add_attribute("new_member", target_addr.to_string())
+                            .add_attribute("weight", weight.to_string());
                     }
                     MemberAction::Remove => {
                         // 再次检查是否为最后一个成员（防止竞态条件）
@@ -884,18 +1784,32 @@ pub fn execute_proposal(
                             return Err(ContractError::CannotRemoveLastMember {});
                         }
 
-                        // 处理被移除成员的历史投票记录
-                        // 注意：我们保持历史投票记录有效，但需要更新活跃提案的投票统计
-                        update_vote_counts_for_removed_member(deps.storage, &target_addr)?;
-
-                        // 移除成员
+                        // 移除成员及其委托关系
+                        // 进行中的提案使用创建时的投票者快照，不受成员变动影响
                         DAO_MEMBERS.remove(deps.storage, target_addr.as_str());
+                        remove_delegations(deps.storage, &target_addr)?;
                         response = response
                             .add_attribute("action", "member_removed")
                             .add_attribute("removed_member", target_addr.to_string());
                     }
                 }
             }
+            ExecutionData::MemberWeightUpdate {
+                member_address,
+                weight,
+            } => {
+                let target_addr = deps.api.addr_validate(member_address)?;
+
+                // 成员可能在投票期间已被移除
+                if !is_dao_member(deps.as_ref(), &target_addr)? {
+                    return Err(ContractError::MemberDoesNotExist {});
+                }
+                DAO_MEMBERS.save(deps.storage, target_addr.as_str(), weight)?;
+                response = response
+                    .add_attribute("action", "member_weight_updated")
+                    .add_attribute("member", target_addr.to_string())
+                    .add_attribute("weight", weight.to_string());
+            }
             ExecutionData::ArticlePublication {
                 ipfs_hash,
                 doi,
@@ -943,6 +1857,7 @@ pub fn execute_proposal(
                 if new_config.approval_threshold == 0 || new_config.approval_threshold > 100 {
                     return Err(ContractError::InvalidVotingThreshold {});
                 }
+                validate_quorum(new_config.quorum)?;
 
                 if new_config.min_members == 0 {
                     return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
@@ -982,10 +1897,106 @@ pub fn execute_proposal(
                         "new_approval_threshold",
                         new_config.approval_threshold.to_string(),
                     )
+                    .add_attribute("old_quorum", old_config.quorum.to_string())
+                    .add_attribute("new_quorum", new_config.quorum.to_string())
                     .add_attribute("old_min_members", old_config.min_members.to_string())
                     .add_attribute("new_min_members", new_config.min_members.to_string())
                     .add_attribute("execution_status", "success");
             }
+            ExecutionData::DenomsUpdate { accepted_denoms } => {
+                validate_denoms(accepted_denoms)?;
+                ensure_denoms_removable(deps.as_ref(), accepted_denoms)?;
+
+                let old_config = PAYMENT_CONFIG.load(deps.storage)?;
+                PAYMENT_CONFIG.save(
+                    deps.storage,
+                    &PaymentConfig {
+                        accepted_denoms: accepted_denoms.clone(),
+                    },
+                )?;
+
+                response = response
+                    .add_attribute("action", "denoms_updated")
+                    .add_attribute("old_accepted_denoms", old_config.accepted_denoms.join(","))
+                    .add_attribute("new_accepted_denoms", accepted_denoms.join(","))
+                    .add_attribute("execution_status", "success");
+            }
+            ExecutionData::FeesUpdate { new_config } => {
+                validate_fee_config(new_config)?;
+                ensure_accepted_denom(deps.as_ref(), &new_config.base_citation_fee.denom)?;
+
+                let old_config = FEE_CONFIG.load(deps.storage)?;
+                FEE_CONFIG.save(deps.storage, new_config)?;
+
+                response = response
+                    .add_attribute("action", "fees_updated")
+                    .add_attribute(
+                        "old_base_citation_fee",
+                        old_config.base_citation_fee.to_string(),
+                    )
+                    .add_attribute(
+                        "new_base_citation_fee",
+                        new_config.base_citation_fee.to_string(),
+                    )
+                    .add_attribute(
+                        "citation_dao_share_bps",
+                        new_config.citation_dao_share_bps.to_string(),
+                    )
+                    .add_attribute(
+                        "access_protocol_fee_bps",
+                        new_config.access_protocol_fee_bps.to_string(),
+                    )
+                    .add_attribute("execution_status", "success");
+            }
+            ExecutionData::BurnToken { token_id, owner } => {
+                // 提案提交后 token 已被转移或销毁时不再执行
+                let current_owner = TOKEN_OWNERS
+                    .load(deps.storage, token_id)
+                    .map_err(|_| ContractError::TokenNotFound {})?;
+                if current_owner != *owner {
+                    return Err(ContractError::NotAuthorized {});
+                }
+
+                burn_token(deps.storage, token_id)?;
+
+                response = response
+                    .add_attribute("action", "token_burned")
+                    .add_attribute("token_id", token_id)
+                    .add_attribute("owner", owner.to_string())
+                    .add_attribute("execution_status", "success");
+            }
+            ExecutionData::TreasurySpend { recipient, amount } => {
+                // 执行时再次检查金库余额，期间可能已有其他支出
+                let recipient = deps.api.addr_validate(recipient)?;
+                debit_treasury(deps.storage, amount)?;
+
+                response = response
+                    .add_message(BankMsg::Send {
+                        to_address: recipient.to_string(),
+                        amount: vec![amount.clone()],
+                    })
+                    .add_attribute("action", "treasury_spent")
+                    .add_attribute("recipient", recipient)
+                    .add_attribute("amount", amount.to_string())
+                    .add_attribute("execution_status", "success");
+            }
+            ExecutionData::Custom { msgs } => {
+                // 执行前再次检查，拒绝旧版本中提交的动用合约资金的消息
+                validate_custom_msgs(msgs)?;
+                // 整批消息通过一次自调用发出，任一消息失败时整批回滚
+                // 以提案 ID 作为 reply ID，失败时在 reply 中将提案标记为失败
+                let dispatch = WasmMsg::Execute {
+                    contract_addr: env.contract.address.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::DispatchCustomMessages { proposal_id })?,
+                    funds: vec![],
+                };
+                // 标记本次执行的提案，批次自调用只能发出该提案的消息
+                PENDING_DISPATCH.save(deps.storage, &proposal_id)?;
+                response = response
+                    .add_submessage(SubMsg::reply_on_error(dispatch, proposal_id))
+                    .add_attribute("action", "custom_messages_dispatched")
+                    .add_attribute("msg_count", msgs.len().to_string());
+            }
         }
     }
 
@@ -996,6 +2007,41 @@ pub fn execute_proposal(
     Ok(response.add_attribute("status", "executed"))
 }
 
+/// 发出自定义提案的全部消息，只能由合约自身在执行该提案时调用
+/// 提案消息中再次调用本接口时标记已被消耗，不能借此发出其他提案的消息
+pub fn execute_dispatch_custom_messages(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address
+        || PENDING_DISPATCH.may_load(deps.storage)? != Some(proposal_id)
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    PENDING_DISPATCH.remove(deps.storage);
+
+    let proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    if proposal.status != ProposalStatus::Executed {
+        return Err(ContractError::Unauthorized {});
+    }
+    let msgs = match proposal.execution_data {
+        Some(ExecutionData::Custom { msgs }) => msgs,
+        _ => {
+            return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+                "Proposal has no custom messages",
+            )))
+        }
+    };
+
+    Ok(Response::new()
+        .add_attribute("method", "dispatch_custom_messages")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("msg_count", msgs.len().to_string())
+        .add_messages(msgs))
+}
+
 /// 计算 DAO 成员数量
 fn count_dao_members(deps: Deps) -> StdResult<u64> {
     let members: StdResult<Vec<_>> = DAO_MEMBERS
@@ -1007,37 +2053,310 @@ fn count_dao_members(deps: Deps) -> StdResult<u64> {
         Err(e) => Err(e),
     }
 }
-/// 检查提案是否通过并更新状态
-/// 处理提案状态的自动更新（通过/拒绝）
-/// 这个函数还会在提案通过时自动触发执行（对于文章发布提案）
-pub fn check_and_update_proposal_status(
-    mut deps: DepsMut,
-    env: Env,
-    proposal_id: u64,
-) -> Result<ProposalStatus, ContractError> {
-    let mut proposal = PROPOSALS
-        .load(deps.storage, proposal_id)
-        .map_err(|_| ContractError::ProposalNotFound {})?;
 
-    // 如果提案已经不是活跃状态，直接返回当前状态
-    if proposal.status != ProposalStatus::Active {
-        return Ok(proposal.status);
-    }
+/// 创建新提案并初始化投票统计
+/// 投票截止时间根据当前 DAO 配置的投票期限计算，配置了押金时从 `info.funds` 中收取
+fn create_proposal(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    proposal_type: ProposalType,
+    title: String,
+    description: String,
+    execution_data: ExecutionData,
+) -> Result<Proposal, ContractError> {
+    // 获取 DAO 配置
+    let dao_config = DAO_CONFIG.load(deps.storage)?;
 
-    // 检查是否过期
-    if env.block.time.seconds() > proposal.voting_end {
-        proposal.status = ProposalStatus::Expired;
+    // 收取提案押金，金额需与要求一致，多付的资金不会被默默留在合约中
+    let deposit = match &dao_config.proposal_deposit {
+        Some(required) => {
+            let paid = must_pay(info, &required.denom)?;
+            if paid < required.amount {
+                return Err(ContractError::InsufficientPayment {});
+            }
+            if paid > required.amount {
+                return Err(ContractError::ExcessPayment {
+                    expected: required.to_string(),
+                });
+            }
+            Some(ProposalDeposit {
+                amount: required.clone(),
+                status: DepositStatus::Held,
+            })
+        }
+        // 不收押金时拒绝附带的资金，避免资金滞留在合约中
+        None => {
+            nonpayable(info)?;
+            None
+        }
+    };
+
+    // 获取下一个提案 ID
+    let proposal_id = PROPOSAL_COUNTER.load(deps.storage)?;
+
+    // 开启同行评审时，文章提案先进入评审阶段，评审完成后才开始投票
+    // 编辑需在评审期限内分配评审人，否则提案过期
+    let review_deadline = match &dao_config.review_config {
+        Some(review_config) if proposal_type == ProposalType::ArticlePublication => {
+            Some(env.block.time.seconds() + review_config.review_period)
+        }
+        _ => None,
+    };
+    let status = if review_deadline.is_some() {
+        ProposalStatus::UnderReview
+    } else {
+        ProposalStatus::Active
+    };
+
+    // 记录开始投票时有资格投票的成员及其投票权，评审中的提案在进入投票时再记录
+    let total_eligible = if status == ProposalStatus::Active {
+        snapshot_voters(deps.storage, env, &dao_config, proposal_id)?
+    } else {
+        0
+    };
+
+    let proposal = Proposal {
+        id: proposal_id,
+        proposer: info.sender.clone(),
+        proposal_type,
+        title,
+        description,
+        created_at: env.block.time.seconds(),
+        voting_end: env.block.time.seconds() + dao_config.voting_period,
+        status,
+        execution_data: Some(execution_data),
+        deposit,
+        snapshot_height: env.block.height,
+        executable_at: None,
+        execution_deadline: None,
+        review_deadline,
+        vote_reveal_period: dao_config.vote_reveal_period,
+        reveal_end: None,
+        review_bounty: None,
+    };
+
+    // 保存提案
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    // 初始化投票统计
+    let vote_count = VoteCount {
+        yes: 0,
+        no: 0,
+        no_with_veto: 0,
+        abstain: 0,
+        total_eligible,
+    };
+    VOTE_COUNTS.save(deps.storage, proposal_id, &vote_count)?;
+
+    // 更新提案计数器
+    PROPOSAL_COUNTER.save(deps.storage, &(proposal_id + 1))?;
+
+    Ok(proposal)
+}
+
+/// 为提案保存当前 DAO 成员的投票权重快照，返回总投票权重
+fn snapshot_voters(
+    storage: &mut dyn cosmwasm_std::Storage,
+    env: &Env,
+    dao_config: &DaoConfig,
+    proposal_id: u64,
+) -> StdResult<u64> {
+    let members: Vec<(String, u64)> = DAO_MEMBERS
+        .range(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+
+    let mut total_power = 0u64;
+    for (member, mut weight) in members {
+        // 按声誉计票时，成员权重加上当前声誉折算的票数
+        if dao_config.voting_weight_source == VotingWeightSource::Reputation {
+            let config = &dao_config.reputation_config;
+            let reputation = reputation_at(
+                storage,
+                &Addr::unchecked(&member),
+                config,
+                env.block.time.seconds(),
+            )?;
+            weight += reputation.score / config.points_per_vote;
+        }
+        PROPOSAL_VOTERS.save(storage, (proposal_id, &member), &weight)?;
+        if let Some(delegate) = DELEGATIONS.may_load(storage, &member)? {
+            PROPOSAL_DELEGATIONS.save(storage, (proposal_id, &member), &delegate)?;
+        }
+        total_power += weight;
+    }
+    resolve_delegated_power(storage, proposal_id)?;
+    Ok(total_power)
+}
+
+/// 评审完成后开始投票：投票期从此时开始计算，并记录此时有资格投票的成员
+fn start_voting(
+    storage: &mut dyn cosmwasm_std::Storage,
+    env: &Env,
+    dao_config: &DaoConfig,
+    proposal: &mut Proposal,
+) -> Result<(), ContractError> {
+    validate_proposal_status_transition(&proposal.status, &ProposalStatus::Active)?;
+    proposal.status = ProposalStatus::Active;
+    proposal.voting_end = env.block.time.seconds() + dao_config.voting_period;
+    proposal.snapshot_height = env.block.height;
+
+    let total_eligible = snapshot_voters(storage, env, dao_config, proposal.id)?;
+    let mut vote_count = VOTE_COUNTS.load(storage, proposal.id)?;
+    vote_count.total_eligible = total_eligible;
+    VOTE_COUNTS.save(storage, proposal.id, &vote_count)?;
+    Ok(())
+}
+
+/// 删除成员发出的委托以及委托给该成员的委托
+fn remove_delegations(storage: &mut dyn cosmwasm_std::Storage, member: &Addr) -> StdResult<()> {
+    DELEGATIONS.remove(storage, member.as_str())?;
+    let delegators: Vec<String> = DELEGATIONS
+        .idx
+        .delegate
+        .prefix(member.clone())
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for delegator in delegators {
+        DELEGATIONS.remove(storage, &delegator)?;
+    }
+    Ok(())
+}
+
+/// 将投票权委托给另一名成员
+/// 委托在之后创建的提案中生效，受托人投票时代为行使委托人的权重
+pub fn execute_delegate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    to: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure_dao_member(deps.as_ref(), &info.sender)?;
+
+    let delegate = deps.api.addr_validate(&to)?;
+    if delegate == info.sender {
+        return Err(ContractError::CannotDelegateToSelf {});
+    }
+    if !is_dao_member(deps.as_ref(), &delegate)? {
+        return Err(ContractError::NotDaoMember {});
+    }
+
+    // 沿受托人的委托链检查，防止形成循环
+    let mut current = delegate.clone();
+    while let Some(next) = DELEGATIONS.may_load(deps.storage, current.as_str())? {
+        if next == info.sender {
+            return Err(ContractError::DelegationCycle {});
+        }
+        current = next;
+    }
+
+    DELEGATIONS.save(deps.storage, info.sender.as_str(), &delegate)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "delegate")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+/// 取消投票委托
+pub fn execute_undelegate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let delegate = DELEGATIONS
+        .may_load(deps.storage, info.sender.as_str())?
+        .ok_or(ContractError::NoDelegation {})?;
+    DELEGATIONS.remove(deps.storage, info.sender.as_str())?;
+
+    Ok(Response::new()
+        .add_attribute("method", "undelegate")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+/// 检查提案是否通过并更新状态
+/// 处理提案状态的自动更新（通过/拒绝）
+/// 这个函数还会在提案通过时自动触发执行（对于文章发布提案）
+pub fn check_and_update_proposal_status(
+    mut deps: DepsMut,
+    env: Env,
+    proposal_id: u64,
+) -> Result<ProposalStatus, ContractError> {
+    let mut proposal = PROPOSALS
+        .load(deps.storage, proposal_id)
+        .map_err(|_| ContractError::ProposalNotFound {})?;
+
+    // 评审截止时间已过仍未进入投票的提案过期，押金可以取回
+    if is_review_expired(&env, &proposal) {
+        validate_proposal_status_transition(&proposal.status, &ProposalStatus::Expired)?;
+        proposal.status = ProposalStatus::Expired;
         PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
-        return Ok(ProposalStatus::Expired);
+        return Ok(proposal.status);
     }
 
-    // 使用新的辅助函数检查通过阈值
-    if check_approval_threshold(deps.as_ref(), proposal_id)? {
+    // 盲审揭示期结束后决定进入投票还是回到评审阶段
+    if proposal.status == ProposalStatus::ReviewReveal {
+        if is_reveal_period_over(&env, &proposal) {
+            close_review_reveal(deps.storage, &env, &mut proposal)?;
+        }
+        return Ok(proposal.status);
+    }
+
+    // 如果提案已经不在投票中，直接返回当前状态
+    if !matches!(
+        proposal.status,
+        ProposalStatus::Active | ProposalStatus::VoteReveal
+    ) {
+        return Ok(proposal.status);
+    }
+
+    // 按时间推进投票阶段，提交-揭示投票在揭示期结束前票数不完整，不提前确定结果
+    let voting_ended = advance_vote_phase(&env, &mut proposal)?;
+    if proposal.vote_reveal_period > 0 && !voting_ended {
+        PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+        return Ok(proposal.status);
+    }
+
+    // 揭示期结束时仍未揭示的投票承诺记为缺席
+    if voting_ended && proposal.vote_reveal_period > 0 {
+        record_vote_no_shows(deps.storage, proposal_id)?;
+    }
+
+    // 投票期结束后按最终票数确定结果
+    if voting_ended {
+        let status = final_proposal_status(deps.as_ref(), proposal_id)?;
+        if status != ProposalStatus::Passed {
+            proposal.status = status.clone();
+            slash_spam_deposit(deps.storage, &mut proposal)?;
+            PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+            return Ok(status);
+        }
+    }
+
+    // 投票期结束时通过，或投票期内结果已确定通过
+    if voting_ended || check_approval_threshold(deps.as_ref(), proposal_id)? {
+        // 通过后需等待时间锁结束才能执行，期间成员可以对结果做出反应
+        // 时间锁结束后有一个投票期的时间执行，过后提案过期
+        let dao_config = DAO_CONFIG.load(deps.storage)?;
+        let execution_delay = dao_config.execution_delay;
+        let executable_at = env.block.time.seconds() + execution_delay;
         proposal.status = ProposalStatus::Passed;
+        proposal.executable_at = Some(executable_at);
+        proposal.execution_deadline = Some(executable_at + dao_config.voting_period);
         PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+        record_reputation(
+            deps.storage,
+            &env,
+            &proposal.proposer,
+            ReputationEvent::ProposalPassed,
+        )?;
 
-        // 对于文章发布提案，自动执行
-        if proposal.proposal_type == ProposalType::ArticlePublication {
+        // 没有时间锁时，文章发布提案自动执行
+        if execution_delay == 0 && proposal.proposal_type == ProposalType::ArticlePublication {
             match try_auto_execute_article_proposal(deps.branch(), env.clone(), &proposal) {
                 Ok(_) => {
                     // 自动执行成功，更新状态为已执行
@@ -1059,6 +2378,7 @@ pub fn check_and_update_proposal_status(
     // 使用新的辅助函数检查是否不可能通过
     if check_impossible_to_pass(deps.as_ref(), proposal_id)? {
         proposal.status = ProposalStatus::Rejected;
+        slash_spam_deposit(deps.storage, &mut proposal)?;
         PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
         return Ok(ProposalStatus::Rejected);
     }
@@ -1067,6 +2387,531 @@ pub fn check_and_update_proposal_status(
     Ok(ProposalStatus::Active)
 }
 
+/// 将未揭示的投票承诺移入缺席记录
+fn record_vote_no_shows(
+    storage: &mut dyn cosmwasm_std::Storage,
+    proposal_id: u64,
+) -> StdResult<()> {
+    let no_shows: Vec<Addr> = VOTE_COMMITMENTS
+        .prefix(proposal_id)
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for voter in &no_shows {
+        VOTE_COMMITMENTS.remove(storage, (proposal_id, voter));
+        VOTE_NO_SHOWS.save(storage, (proposal_id, voter), &Empty {})?;
+    }
+    Ok(())
+}
+
+/// 投票期结束后确定提案结果
+/// 通过的文章发布提案会像投票期内通过时一样自动执行
+pub fn execute_finalize_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    match proposal.status {
+        ProposalStatus::Active => {
+            if env.block.time.seconds() <= proposal.voting_end {
+                return Err(ContractError::VotingPeriodActive {});
+            }
+        }
+        // 评审超时的提案由任何人标记为过期
+        ProposalStatus::UnderReview => {
+            if !is_review_expired(&env, &proposal) {
+                return Err(ContractError::ReviewPeriodActive {});
+            }
+        }
+        // 揭示期结束后同样由任何人推进
+        ProposalStatus::ReviewReveal | ProposalStatus::VoteReveal => {
+            if !is_reveal_period_over(&env, &proposal) {
+                return Err(ContractError::RevealPeriodActive {});
+            }
+        }
+        _ => {
+            return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+                "Proposal is not active",
+            )))
+        }
+    }
+
+    let status = check_and_update_proposal_status(deps, env, proposal_id)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "finalize_proposal")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("finalizer", info.sender)
+        .add_attribute("proposal_status", format!("{:?}", status)))
+}
+
+/// 验证提案者可以修改提案：提案仍在投票中且投票期未结束
+fn ensure_proposer_can_modify(
+    env: &Env,
+    info: &MessageInfo,
+    proposal: &Proposal,
+) -> Result<(), ContractError> {
+    if proposal.proposer != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_can_vote_on_proposal(env, proposal)
+}
+
+/// 提案者撤回提案，已有投票或投票承诺后不能撤回，避免逃避否决票的押金没收
+/// 撤回后押金可以通过 `ClaimDeposit` 取回
+pub fn execute_withdraw_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let mut proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    if matches!(
+        proposal.status,
+        ProposalStatus::UnderReview | ProposalStatus::ReviewReveal
+    ) {
+        // 评审阶段还未开始投票，提案者同样可以撤回
+        if proposal.proposer != info.sender {
+            return Err(ContractError::Unauthorized {});
+        }
+    } else {
+        ensure_proposer_can_modify(&env, &info, &proposal)?;
+    }
+
+    let has_votes = VOTES
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .next()
+        .is_some()
+        || VOTE_COMMITMENTS
+            .prefix(proposal_id)
+            .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .next()
+            .is_some();
+    if has_votes {
+        return Err(ContractError::ProposalHasVotes {});
+    }
+
+    validate_proposal_status_transition(&proposal.status, &ProposalStatus::Withdrawn)?;
+    proposal.status = ProposalStatus::Withdrawn;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    let vote_count = VOTE_COUNTS.load(deps.storage, proposal_id)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "withdraw_proposal")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("proposer", info.sender)
+        .add_attribute("votes_cast", total_voted(&vote_count).to_string())
+        .add_attribute("proposal_status", format!("{:?}", proposal.status)))
+}
+
+/// 提案者修改提案的执行数据
+/// 修改后的数据必须与原提案类型一致并重新验证，已有投票和否决被清空，投票截止时间不变
+pub fn execute_amend_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    execution_data: ExecutionData,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let mut proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    ensure_proposer_can_modify(&env, &info, &proposal)?;
+
+    let execution_data = match (&proposal.proposal_type, execution_data) {
+        (
+            ProposalType::ArticlePublication,
+            ExecutionData::ArticlePublication {
+                ipfs_hash,
+                doi,
+                metadata_uri,
+            },
+        ) => {
+            // 评审人审阅的是提交时的内容，评审后修改会绕过同行评审
+            if REVIEWS
+                .prefix(proposal_id)
+                .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+                .next()
+                .is_some()
+            {
+                return Err(ContractError::ReviewedProposalLocked {});
+            }
+            let doi = validate_article_data(deps.as_ref(), &ipfs_hash, &doi, &metadata_uri)?;
+            ExecutionData::ArticlePublication {
+                ipfs_hash,
+                doi,
+                metadata_uri,
+            }
+        }
+        (
+            ProposalType::AddMember,
+            ExecutionData::MemberChange {
+                member_address,
+                action: MemberAction::Add,
+                weight,
+            },
+        )
+        | (
+            ProposalType::RemoveMember,
+            ExecutionData::MemberChange {
+                member_address,
+                action: MemberAction::Remove,
+                weight,
+            },
+        ) => {
+            let action = match proposal.proposal_type {
+                ProposalType::AddMember => MemberAction::Add,
+                _ => MemberAction::Remove,
+            };
+            let (target_addr, weight) =
+                validate_member_change(deps.as_ref(), &member_address, &action, weight)?;
+            ExecutionData::MemberChange {
+                member_address: target_addr.to_string(),
+                action,
+                weight,
+            }
+        }
+        (
+            ProposalType::UpdateMemberWeight,
+            ExecutionData::MemberWeightUpdate {
+                member_address,
+                weight,
+            },
+        ) => {
+            let target_addr =
+                validate_member_weight_update(deps.as_ref(), &member_address, weight)?;
+            ExecutionData::MemberWeightUpdate {
+                member_address: target_addr.to_string(),
+                weight,
+            }
+        }
+        (ProposalType::UpdateDenoms, ExecutionData::DenomsUpdate { accepted_denoms }) => {
+            validate_denoms(&accepted_denoms)?;
+            ensure_denoms_removable(deps.as_ref(), &accepted_denoms)?;
+            ExecutionData::DenomsUpdate { accepted_denoms }
+        }
+        (ProposalType::TreasurySpend, ExecutionData::TreasurySpend { recipient, amount }) => {
+            let recipient = validate_treasury_spend(deps.as_ref(), &recipient, &amount)?;
+            ExecutionData::TreasurySpend {
+                recipient: recipient.to_string(),
+                amount,
+            }
+        }
+        (ProposalType::Custom, ExecutionData::Custom { msgs }) => {
+            validate_custom_msgs(&msgs)?;
+            ExecutionData::Custom { msgs }
+        }
+        // 配置、费用和销毁提案需撤回后重新提交
+        _ => return Err(ContractError::InvalidAmendment {}),
+    };
+
+    // 清空投票前按现有票数执行垃圾提案押金没收，修改不能用来逃避否决票
+    slash_spam_deposit(deps.storage, &mut proposal)?;
+
+    // 清空已有投票和否决
+    let voters: Vec<String> = VOTES
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for voter in &voters {
+        VOTES.remove(deps.storage, (proposal_id, voter));
+    }
+    let vetoers: Vec<Addr> = VETOES
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for vetoer in &vetoers {
+        VETOES.remove(deps.storage, (proposal_id, vetoer));
+    }
+    let committers: Vec<Addr> = VOTE_COMMITMENTS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for committer in &committers {
+        VOTE_COMMITMENTS.remove(deps.storage, (proposal_id, committer));
+    }
+    resolve_delegated_power(deps.storage, proposal_id)?;
+    let vote_count = tally_votes(deps.storage, proposal_id)?;
+    VOTE_COUNTS.save(deps.storage, proposal_id, &vote_count)?;
+
+    // 保留原投票截止时间，反复修改不能无限延长投票
+    let previous_execution_data = proposal.execution_data.replace(execution_data.clone());
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "amend_proposal")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("proposer", info.sender)
+        .add_attribute(
+            "previous_execution_data",
+            cosmwasm_std::to_json_string(&previous_execution_data)?,
+        )
+        .add_attribute(
+            "execution_data",
+            cosmwasm_std::to_json_string(&execution_data)?,
+        )
+        .add_attribute(
+            "cleared_votes",
+            (voters.len() + committers.len()).to_string(),
+        )
+        .add_attribute("voting_end", proposal.voting_end.to_string()))
+}
+
+/// 否决委员会成员否决提案
+/// 提案在投票期间或通过后的时间锁期间可被否决，达到委员会门槛后状态变为 Vetoed，押金归 DAO 所有
+pub fn execute_veto_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    reason: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let council = DAO_CONFIG
+        .load(deps.storage)?
+        .veto_council
+        .filter(|council| council.members.contains(&info.sender))
+        .ok_or(ContractError::NotVetoCouncilMember {})?;
+
+    if reason.trim().is_empty() {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Veto reason cannot be empty",
+        )));
+    }
+
+    let mut proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    let in_timelock = proposal
+        .executable_at
+        .is_some_and(|executable_at| env.block.time.seconds() < executable_at);
+    // 只能在投票期、揭示期或时间锁期间否决，期限过后等待确定结果
+    match proposal.status {
+        ProposalStatus::Active if !is_proposal_expired(&env, &proposal) => {}
+        ProposalStatus::VoteReveal if !is_reveal_period_over(&env, &proposal) => {}
+        ProposalStatus::Passed if in_timelock => {}
+        _ => return Err(ContractError::CannotVetoProposal {}),
+    }
+
+    if VETOES.has(deps.storage, (proposal_id, &info.sender)) {
+        return Err(ContractError::AlreadyVetoed {});
+    }
+    VETOES.save(deps.storage, (proposal_id, &info.sender), &reason)?;
+
+    // 只统计仍在委员会中的成员的否决
+    let vetoes = VETOES
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .filter(|member| council.members.contains(member))
+        .count() as u64;
+
+    if vetoes >= council.threshold {
+        validate_proposal_status_transition(&proposal.status, &ProposalStatus::Vetoed)?;
+        proposal.status = ProposalStatus::Vetoed;
+        slash_deposit(deps.storage, &mut proposal)?;
+        PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "veto_proposal")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("council", council.name)
+        .add_attribute("member", info.sender)
+        .add_attribute("reason", reason)
+        .add_attribute("vetoes", vetoes.to_string())
+        .add_attribute("threshold", council.threshold.to_string())
+        .add_attribute("proposal_status", format!("{:?}", proposal.status)))
+}
+
+/// 未通过的提案若被超过三分之一的投票认定为垃圾提案，且配置允许，则没收押金
+fn slash_spam_deposit(
+    storage: &mut dyn cosmwasm_std::Storage,
+    proposal: &mut Proposal,
+) -> Result<(), ContractError> {
+    let dao_config = DAO_CONFIG.load(storage)?;
+    if !dao_config.slash_spam_deposits {
+        return Ok(());
+    }
+
+    let vote_count = VOTE_COUNTS.load(storage, proposal.id)?;
+    if vote_count.no_with_veto * 3 > total_voted(&vote_count) {
+        slash_deposit(storage, proposal)?;
+    }
+    Ok(())
+}
+
+/// 没收仍在托管中的押金并计入 DAO 金库
+fn slash_deposit(
+    storage: &mut dyn cosmwasm_std::Storage,
+    proposal: &mut Proposal,
+) -> StdResult<()> {
+    if let Some(deposit) = proposal.deposit.as_mut() {
+        if deposit.status == DepositStatus::Held {
+            deposit.status = DepositStatus::Slashed;
+            credit_treasury(storage, &deposit.amount.denom, deposit.amount.amount)?;
+        }
+    }
+    Ok(())
+}
+
+/// 增加 DAO 金库余额
+fn credit_treasury(
+    storage: &mut dyn cosmwasm_std::Storage,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<()> {
+    if amount.is_zero() {
+        return Ok(());
+    }
+    TREASURY.update(storage, denom, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(())
+}
+
+/// 从 DAO 金库扣除资金，余额不足时返回错误
+fn debit_treasury(
+    storage: &mut dyn cosmwasm_std::Storage,
+    amount: &Coin,
+) -> Result<(), ContractError> {
+    let balance = TREASURY
+        .may_load(storage, &amount.denom)?
+        .unwrap_or_default();
+    let remaining = balance.checked_sub(amount.amount).map_err(|_| {
+        ContractError::InsufficientTreasuryFunds {
+            denom: amount.denom.clone(),
+        }
+    })?;
+    if remaining.is_zero() {
+        TREASURY.remove(storage, &amount.denom);
+    } else {
+        TREASURY.save(storage, &amount.denom, &remaining)?;
+    }
+    Ok(())
+}
+
+/// 验证金库支出：接收地址有效，金额大于 0 且不超过当前金库余额
+fn validate_treasury_spend(
+    deps: Deps,
+    recipient: &str,
+    amount: &Coin,
+) -> Result<Addr, ContractError> {
+    let recipient = deps.api.addr_validate(recipient)?;
+    if amount.amount.is_zero() {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Spend amount must be greater than zero",
+        )));
+    }
+    let balance = TREASURY
+        .may_load(deps.storage, &amount.denom)?
+        .unwrap_or_default();
+    if balance < amount.amount {
+        return Err(ContractError::InsufficientTreasuryFunds {
+            denom: amount.denom.clone(),
+        });
+    }
+    Ok(recipient)
+}
+
+/// 提交从 DAO 金库支出资金的提案
+pub fn execute_submit_treasury_spend_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Coin,
+    title: String,
+    description: String,
+) -> Result<Response, ContractError> {
+    // 验证提案者是 DAO 成员
+    ensure_dao_member(deps.as_ref(), &info.sender)?;
+
+    if title.trim().is_empty() {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Title cannot be empty",
+        )));
+    }
+    let recipient = validate_treasury_spend(deps.as_ref(), &recipient, &amount)?;
+
+    let proposal = create_proposal(
+        deps,
+        &env,
+        &info,
+        ProposalType::TreasurySpend,
+        title,
+        description,
+        ExecutionData::TreasurySpend {
+            recipient: recipient.to_string(),
+            amount: amount.clone(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "submit_treasury_spend_proposal")
+        .add_attribute("proposal_id", proposal.id.to_string())
+        .add_attribute("proposer", info.sender.to_string())
+        .add_attribute("recipient", recipient)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("voting_end", proposal.voting_end.to_string())
+        .add_attribute("proposal_type", "treasury_spend"))
+}
+
+/// 提案结束后提案者取回押金
+/// 通过、拒绝、过期和已执行的提案均可退还，被没收的押金留在合约中归 DAO 所有
+pub fn execute_claim_deposit(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    if proposal.proposer != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // 先更新提案状态，投票期结束的提案会被标记为过期
+    let status = check_and_update_proposal_status(deps.branch(), env, proposal_id)?;
+    if matches!(
+        status,
+        ProposalStatus::Active
+            | ProposalStatus::UnderReview
+            | ProposalStatus::ReviewReveal
+            | ProposalStatus::VoteReveal
+    ) {
+        return Err(ContractError::VotingPeriodActive {});
+    }
+
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    let deposit = proposal
+        .deposit
+        .as_mut()
+        .ok_or(ContractError::NoDeposit {})?;
+    match deposit.status {
+        DepositStatus::Held => {}
+        DepositStatus::Refunded => return Err(ContractError::DepositAlreadyRefunded {}),
+        DepositStatus::Slashed => return Err(ContractError::DepositSlashed {}),
+    }
+    deposit.status = DepositStatus::Refunded;
+    let amount = deposit.amount.clone();
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![amount.clone()],
+        })
+        .add_attribute("method", "claim_deposit")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("proposer", info.sender)
+        .add_attribute("amount", amount.to_string()))
+}
+
 /// 尝试自动执行文章发布提案
 /// 这个函数在提案通过时被调用，尝试自动执行文章发布
 fn try_auto_execute_article_proposal(
@@ -1100,7 +2945,7 @@ fn try_auto_execute_article_proposal(
 /// 执行文章发布提案的自动执行逻辑
 /// 这个函数专门处理通过的文章发布提案的执行，集成现有的 create_paper_item 功能
 pub fn execute_article_publication_proposal(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     proposal: &Proposal,
     ipfs_hash: String,
@@ -1126,53 +2971,45 @@ pub fn execute_article_publication_proposal(
         )));
     }
 
-    // 检查 DOI 是否已经存在（防止重复发布）
-    let existing_papers: StdResult<Vec<_>> = PAPER_DOIS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .collect();
+    // 检查 DOI 是否已经存在（防止提案期间被其他途径重复发布）
+    ensure_doi_available(deps.as_ref(), &doi)?;
 
-    if let Ok(papers) = existing_papers {
-        for (_, existing_doi) in papers {
-            if existing_doi == doi {
-                return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
-                    format!("DOI {} already exists", doi),
-                )));
-            }
-        }
-    }
-
-    // 集成现有的 create_paper_item 功能
     // 使用提案者作为文章的所有者
-    let paper_creation_info = MessageInfo {
-        sender: proposal.proposer.clone(),
-        funds: vec![], // 文章发布不需要资金
-    };
-
-    // 调用现有的 create_paper_item 函数
-    match execute_create_paper_item(
-        deps,
-        env,
-        paper_creation_info,
+    let (token_id, doi) = create_paper(
+        deps.branch(),
+        &env,
+        &proposal.proposer,
         ipfs_hash.clone(),
-        doi.clone(),
-        metadata_uri.clone(),
-    ) {
-        Ok(mut paper_response) => {
-            // 添加 DAO 执行相关的属性
-            paper_response = paper_response
-                .add_attribute("dao_approved", "true")
-                .add_attribute("proposal_id", proposal.id.to_string())
-                .add_attribute("execution_method", "dao_proposal");
-
-            Ok(paper_response)
-        }
-        Err(e) => {
-            // 记录执行失败的详细信息
-            Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
-                format!("Failed to create paper item: {}", e),
-            )))
-        }
-    }
+        &doi,
+        metadata_uri,
+    )
+    .map_err(|e| {
+        // 记录执行失败的详细信息
+        ContractError::Std(cosmwasm_std::StdError::generic_err(format!(
+            "Failed to create paper item: {}",
+            e
+        )))
+    })?;
+
+    // 只有 DAO 批准发表的论文计入声誉，之后被引用也才计分
+    APPROVED_PAPERS.save(deps.storage, &token_id, &proposal.id)?;
+    record_reputation(
+        deps.storage,
+        &env,
+        &proposal.proposer,
+        ReputationEvent::PaperPublished,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "create_paper_item")
+        .add_attribute("token_id", token_id)
+        .add_attribute("owner", proposal.proposer.clone())
+        .add_attribute("ipfs_hash", ipfs_hash)
+        .add_attribute("paper_doi", doi)
+        .add_attribute("paper_type", "academic_paper")
+        .add_attribute("dao_approved", "true")
+        .add_attribute("proposal_id", proposal.id.to_string())
+        .add_attribute("execution_method", "dao_proposal"))
 }
 
 /// 实现投票功能
@@ -1187,12 +3024,15 @@ pub fn execute_vote_on_proposal(
     proposal_id: u64,
     choice: VoteChoice,
 ) -> Result<Response, ContractError> {
-    // 验证投票者的 DAO 成员身份
-    ensure_dao_member(deps.as_ref(), &info.sender)?;
-
     // 检查提案是否存在并验证可以投票
     let proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
     ensure_can_vote_on_proposal(&env, &proposal)?;
+    ensure_open_voting(&proposal)?;
+
+    // 只有提案创建时快照中的成员可以投票，投票权以快照为准
+    if !PROPOSAL_VOTERS.has(deps.storage, (proposal_id, info.sender.as_str())) {
+        return Err(ContractError::NotDaoMember {});
+    }
 
     // 创建投票记录
     let vote = Vote {
@@ -1205,31 +3045,18 @@ pub fn execute_vote_on_proposal(
     let vote_key = (proposal_id, info.sender.as_str());
     let previous_vote = VOTES.may_load(deps.storage, vote_key)?;
 
+    // 增量更新投票统计：受托人的投票带上委托人的权重，委托人直接投票时覆盖受托人的选择
+    let vote_count = apply_vote(
+        deps.storage,
+        proposal_id,
+        info.sender.as_str(),
+        previous_vote.as_ref().map(|vote| &vote.choice),
+        &choice,
+    )?;
+
     // 保存新的投票记录
     VOTES.save(deps.storage, vote_key, &vote)?;
 
-    // 实时更新投票统计
-    let mut vote_count = VOTE_COUNTS.load(deps.storage, proposal_id)?;
-
-    // 如果是重复投票，先减去之前的投票
-    if let Some(ref prev_vote) = previous_vote {
-        match prev_vote.choice {
-            VoteChoice::Yes => vote_count.yes -= 1,
-            VoteChoice::No => vote_count.no -= 1,
-            VoteChoice::Abstain => vote_count.abstain -= 1,
-        }
-    }
-
-    // 添加新的投票
-    match choice {
-        VoteChoice::Yes => vote_count.yes += 1,
-        VoteChoice::No => vote_count.no += 1,
-        VoteChoice::Abstain => vote_count.abstain += 1,
-    }
-
-    // 保存更新后的投票统计
-    VOTE_COUNTS.save(deps.storage, proposal_id, &vote_count)?;
-
     // 检查并更新提案状态（如果达到通过阈值或不可能通过）
     let updated_status = check_and_update_proposal_status(deps, env, proposal_id)?;
 
@@ -1247,14 +3074,98 @@ pub fn execute_vote_on_proposal(
         response = response.add_attribute("vote_updated", "false");
     }
 
-    // 添加当前投票统计信息
-    response = response
+    // 添加当前投票统计信息
+    response = response
+        .add_attribute("yes_votes", vote_count.yes.to_string())
+        .add_attribute("no_votes", vote_count.no.to_string())
+        .add_attribute("no_with_veto_votes", vote_count.no_with_veto.to_string())
+        .add_attribute("abstain_votes", vote_count.abstain.to_string())
+        .add_attribute("total_eligible", vote_count.total_eligible.to_string());
+
+    Ok(response)
+}
+
+/// 提交-揭示投票的提交期内提交投票承诺
+/// 承诺在揭示前不计入票数，提交期内可以重新提交覆盖
+pub fn execute_commit_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    commitment: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    ensure_can_commit_vote(&env, &proposal)?;
+    if !PROPOSAL_VOTERS.has(deps.storage, (proposal_id, info.sender.as_str())) {
+        return Err(ContractError::NotDaoMember {});
+    }
+
+    let commitment = normalize_commitment(&commitment)?;
+    let previous = VOTE_COMMITMENTS.may_load(deps.storage, (proposal_id, &info.sender))?;
+    VOTE_COMMITMENTS.save(deps.storage, (proposal_id, &info.sender), &commitment)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "commit_vote")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", info.sender)
+        .add_attribute("commitment_updated", previous.is_some().to_string())
+        .add_attribute("reveal_starts_after", proposal.voting_end.to_string()))
+}
+
+/// 揭示期内公开投票选择，与承诺一致时计入票数
+/// 揭示期结束前不提前确定结果，未揭示的承诺视为未投票
+pub fn execute_reveal_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    choice: VoteChoice,
+    salt: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    // 提交期结束后第一个揭示的成员负责把提案推进到揭示期
+    let mut proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    advance_vote_phase(&env, &mut proposal)?;
+    ensure_in_reveal_phase(&env, &proposal, ProposalStatus::VoteReveal)?;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    let commitment = VOTE_COMMITMENTS
+        .may_load(deps.storage, (proposal_id, &info.sender))?
+        .ok_or(ContractError::NoCommitment {})?;
+    if vote_commitment(proposal_id, &info.sender, &choice, &salt) != commitment {
+        return Err(ContractError::CommitmentMismatch {});
+    }
+
+    let vote_count = apply_vote(
+        deps.storage,
+        proposal_id,
+        info.sender.as_str(),
+        None,
+        &choice,
+    )?;
+    VOTES.save(
+        deps.storage,
+        (proposal_id, info.sender.as_str()),
+        &Vote {
+            voter: info.sender.clone(),
+            choice: choice.clone(),
+            timestamp: env.block.time.seconds(),
+        },
+    )?;
+    VOTE_COMMITMENTS.remove(deps.storage, (proposal_id, &info.sender));
+
+    Ok(Response::new()
+        .add_attribute("method", "reveal_vote")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", info.sender)
+        .add_attribute("choice", format!("{:?}", choice))
         .add_attribute("yes_votes", vote_count.yes.to_string())
         .add_attribute("no_votes", vote_count.no.to_string())
-        .add_attribute("abstain_votes", vote_count.abstain.to_string())
-        .add_attribute("total_eligible", vote_count.total_eligible.to_string());
-
-    Ok(response)
+        .add_attribute("no_with_veto_votes", vote_count.no_with_veto.to_string())
+        .add_attribute("abstain_votes", vote_count.abstain.to_string()))
 }
 
 /// 计算投票统计
@@ -1268,8 +3179,8 @@ pub fn calculate_vote_statistics(deps: Deps, proposal_id: u64) -> Result<VoteCou
     Ok(vote_count)
 }
 
-/// 实现半数以上通过阈值的检查逻辑
-/// 检查提案是否达到通过阈值
+/// 检查提案是否已确定通过
+/// 已达到法定人数，且即使剩余成员全部反对，赞成票比例仍达到通过阈值
 pub fn check_approval_threshold(deps: Deps, proposal_id: u64) -> Result<bool, ContractError> {
     let vote_count = VOTE_COUNTS
         .load(deps.storage, proposal_id)
@@ -1277,16 +3188,17 @@ pub fn check_approval_threshold(deps: Deps, proposal_id: u64) -> Result<bool, Co
 
     let dao_config = DAO_CONFIG.load(deps.storage)?;
 
-    // 计算通过阈值（向上取整）
-    let required_yes_votes =
-        (vote_count.total_eligible * dao_config.approval_threshold).div_ceil(100);
+    let remaining_votes = vote_count
+        .total_eligible
+        .saturating_sub(total_voted(&vote_count));
+    let against = vote_count.no + vote_count.no_with_veto + remaining_votes;
 
-    // 检查是否达到通过阈值
-    Ok(vote_count.yes >= required_yes_votes)
+    Ok(quorum_reached(&vote_count, dao_config.quorum)
+        && threshold_reached(vote_count.yes, against, dao_config.approval_threshold))
 }
 
 /// 检查提案是否不可能通过
-/// 即使剩余所有成员都投赞成票也无法达到阈值
+/// 即使剩余所有成员都投赞成票，赞成票比例也无法达到阈值
 pub fn check_impossible_to_pass(deps: Deps, proposal_id: u64) -> Result<bool, ContractError> {
     let vote_count = VOTE_COUNTS
         .load(deps.storage, proposal_id)
@@ -1294,19 +3206,41 @@ pub fn check_impossible_to_pass(deps: Deps, proposal_id: u64) -> Result<bool, Co
 
     let dao_config = DAO_CONFIG.load(deps.storage)?;
 
-    // 计算通过阈值
-    let required_yes_votes =
-        (vote_count.total_eligible * dao_config.approval_threshold).div_ceil(100);
+    let remaining_votes = vote_count
+        .total_eligible
+        .saturating_sub(total_voted(&vote_count));
+    let max_possible_yes = vote_count.yes + remaining_votes;
+    let against = vote_count.no + vote_count.no_with_veto;
+
+    Ok(!threshold_reached(
+        max_possible_yes,
+        against,
+        dao_config.approval_threshold,
+    ))
+}
 
-    // 计算已投票数
-    let total_voted = vote_count.yes + vote_count.no + vote_count.abstain;
-    let remaining_votes = vote_count.total_eligible - total_voted;
+/// 投票期结束时的最终结果
+/// 未达到法定人数视为过期，达到法定人数后按赞成票比例决定通过或拒绝
+pub fn final_proposal_status(
+    deps: Deps,
+    proposal_id: u64,
+) -> Result<ProposalStatus, ContractError> {
+    let vote_count = VOTE_COUNTS
+        .load(deps.storage, proposal_id)
+        .map_err(|_| ContractError::ProposalNotFound {})?;
 
-    // 计算最大可能的赞成票数
-    let max_possible_yes = vote_count.yes + remaining_votes;
+    let dao_config = DAO_CONFIG.load(deps.storage)?;
+
+    if !quorum_reached(&vote_count, dao_config.quorum) {
+        return Ok(ProposalStatus::Expired);
+    }
 
-    // 如果最大可能的赞成票数仍然小于所需阈值，则不可能通过
-    Ok(max_possible_yes < required_yes_votes)
+    let against = vote_count.no + vote_count.no_with_veto;
+    if threshold_reached(vote_count.yes, against, dao_config.approval_threshold) {
+        Ok(ProposalStatus::Passed)
+    } else {
+        Ok(ProposalStatus::Rejected)
+    }
 }
 
 /// 获取提案的详细投票统计信息
@@ -1318,45 +3252,16 @@ pub fn get_detailed_vote_statistics(
     let passed = check_approval_threshold(deps, proposal_id)?;
     let impossible = check_impossible_to_pass(deps, proposal_id)?;
 
+    // 达到法定人数所需的投票数（向上取整）
     let dao_config = DAO_CONFIG.load(deps.storage)?;
-    let required_yes_votes =
-        (vote_count.total_eligible * dao_config.approval_threshold).div_ceil(100);
-
-    Ok((vote_count, passed, impossible, required_yes_votes))
-}
+    let required_votes = (vote_count.total_eligible * dao_config.quorum).div_ceil(100);
 
-/// 更新活跃提案的投票统计，当成员被移除时
-fn update_vote_counts_for_removed_member(
-    storage: &mut dyn cosmwasm_std::Storage,
-    _removed_member: &Addr,
-) -> Result<(), ContractError> {
-    // 获取所有活跃提案并更新其投票统计中的 total_eligible 数量
-    let proposals: StdResult<Vec<_>> = PROPOSALS
-        .range(storage, None, None, cosmwasm_std::Order::Ascending)
-        .collect();
-
-    match proposals {
-        Ok(proposal_list) => {
-            for (proposal_id, proposal) in proposal_list {
-                // 只处理活跃状态的提案
-                if proposal.status == ProposalStatus::Active {
-                    if let Ok(mut vote_count) = VOTE_COUNTS.load(storage, proposal_id) {
-                        // 减少总有效投票者数量
-                        if vote_count.total_eligible > 0 {
-                            vote_count.total_eligible -= 1;
-                            VOTE_COUNTS.save(storage, proposal_id, &vote_count)?;
-                        }
-                    }
-                }
-            }
-            Ok(())
-        }
-        Err(e) => Err(ContractError::Std(e)),
-    }
+    Ok((vote_count, passed, impossible, required_votes))
 }
 
 /// 更新 DAO 配置
 /// 只有 DAO 成员可以提交配置更新提案
+#[allow(clippy::too_many_arguments)]
 pub fn execute_update_dao_config(
     deps: DepsMut,
     env: Env,
@@ -1364,12 +3269,72 @@ pub fn execute_update_dao_config(
     voting_period: Option<u64>,
     approval_threshold: Option<u64>,
     min_members: Option<u64>,
+    quorum: Option<u64>,
+    proposal_deposit: Option<Coin>,
+    slash_spam_deposits: Option<bool>,
+    execution_delay: Option<u64>,
+    veto_council: Option<VetoCouncilMsg>,
+    review_config: Option<ReviewConfigMsg>,
+    vote_reveal_period: Option<u64>,
+    reputation_config: Option<ReputationConfig>,
+    voting_weight_source: Option<VotingWeightSource>,
 ) -> Result<Response, ContractError> {
     // 验证提案者是 DAO 成员
     ensure_dao_member(deps.as_ref(), &info.sender)?;
 
     // 验证配置参数的有效性
     validate_dao_config(voting_period, approval_threshold, min_members)?;
+    if let Some(quorum) = quorum {
+        validate_quorum(quorum)?;
+    }
+    if let Some(delay) = execution_delay {
+        validate_execution_delay(delay)?;
+    }
+    let veto_council = veto_council
+        .map(|council| -> StdResult<_> {
+            let members = council
+                .members
+                .iter()
+                .map(|member| deps.api.addr_validate(member))
+                .collect::<StdResult<Vec<_>>>()?;
+            Ok(VetoCouncil {
+                name: council.name,
+                members,
+                threshold: council.threshold,
+            })
+        })
+        .transpose()?;
+    if let Some(council) = &veto_council {
+        if !council.members.is_empty() {
+            validate_veto_council(council)?;
+        }
+    }
+    if let Some(period) = vote_reveal_period {
+        validate_reveal_period(period)?;
+    }
+    if let Some(reputation) = &reputation_config {
+        validate_reputation_config(reputation)?;
+    }
+    let review_config = review_config
+        .map(|review| -> StdResult<_> {
+            let editors = review
+                .editors
+                .iter()
+                .map(|editor| deps.api.addr_validate(editor))
+                .collect::<StdResult<Vec<_>>>()?;
+            Ok(ReviewConfig {
+                editors,
+                required_reviews: review.required_reviews,
+                review_period: review.review_period,
+                reveal_period: review.reveal_period,
+            })
+        })
+        .transpose()?;
+    if let Some(review) = &review_config {
+        if !review.editors.is_empty() {
+            validate_review_config(review)?;
+        }
+    }
 
     // 获取当前配置
     let current_config = DAO_CONFIG.load(deps.storage)?;
@@ -1379,6 +3344,35 @@ pub fn execute_update_dao_config(
         voting_period: voting_period.unwrap_or(current_config.voting_period),
         approval_threshold: approval_threshold.unwrap_or(current_config.approval_threshold),
         min_members: min_members.unwrap_or(current_config.min_members),
+        quorum: quorum.unwrap_or(current_config.quorum),
+        proposal_deposit: match proposal_deposit {
+            // 数量为 0 表示取消押金
+            Some(deposit) if deposit.amount.is_zero() => None,
+            Some(deposit) => {
+                ensure_accepted_denom(deps.as_ref(), &deposit.denom)?;
+                Some(deposit)
+            }
+            None => current_config.proposal_deposit.clone(),
+        },
+        slash_spam_deposits: slash_spam_deposits.unwrap_or(current_config.slash_spam_deposits),
+        execution_delay: execution_delay.unwrap_or(current_config.execution_delay),
+        veto_council: match veto_council {
+            // 成员列表为空表示取消否决委员会
+            Some(council) if council.members.is_empty() => None,
+            Some(council) => Some(council),
+            None => current_config.veto_council.clone(),
+        },
+        review_config: match review_config {
+            // 编辑列表为空表示取消同行评审
+            Some(review) if review.editors.is_empty() => None,
+            Some(review) => Some(review),
+            None => current_config.review_config.clone(),
+        },
+        vote_reveal_period: vote_reveal_period.unwrap_or(current_config.vote_reveal_period),
+        reputation_config: reputation_config
+            .unwrap_or_else(|| current_config.reputation_config.clone()),
+        voting_weight_source: voting_weight_source
+            .unwrap_or_else(|| current_config.voting_weight_source.clone()),
     };
 
     // 验证新的投票期限
@@ -1395,50 +3389,33 @@ pub fn execute_update_dao_config(
         )));
     }
 
-    // 获取下一个提案 ID
-    let proposal_id = PROPOSAL_COUNTER.load(deps.storage)?;
-
-    // 设置提案的投票截止时间
-    let voting_end = env.block.time.seconds() + current_config.voting_period;
-
     // 创建配置更新提案
-    let proposal = Proposal {
-        id: proposal_id,
-        proposer: info.sender.clone(),
-        proposal_type: ProposalType::UpdateConfig,
-        title: "DAO Configuration Update".to_string(),
-        description: format!(
-            "Update DAO configuration - Voting Period: {} -> {}, Approval Threshold: {}% -> {}%, Min Members: {} -> {}",
-            current_config.voting_period,
-            new_config.voting_period,
-            current_config.approval_threshold,
-            new_config.approval_threshold,
-            current_config.min_members,
-            new_config.min_members
-        ),
-        created_at: env.block.time.seconds(),
-        voting_end,
-        status: ProposalStatus::Active,
-        execution_data: Some(ExecutionData::ConfigUpdate {
+    let description = format!(
+        "Update DAO configuration - Voting Period: {} -> {}, Approval Threshold: {}% -> {}%, Quorum: {}% -> {}%, Min Members: {} -> {}, Execution Delay: {} -> {}",
+        current_config.voting_period,
+        new_config.voting_period,
+        current_config.approval_threshold,
+        new_config.approval_threshold,
+        current_config.quorum,
+        new_config.quorum,
+        current_config.min_members,
+        new_config.min_members,
+        current_config.execution_delay,
+        new_config.execution_delay
+    );
+    let proposal = create_proposal(
+        deps,
+        &env,
+        &info,
+        ProposalType::UpdateConfig,
+        "DAO Configuration Update".to_string(),
+        description,
+        ExecutionData::ConfigUpdate {
             new_config: new_config.clone(),
-        }),
-    };
-
-    // 保存提案
-    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
-
-    // 初始化投票统计
-    let member_count = count_dao_members(deps.as_ref())?;
-    let vote_count = VoteCount {
-        yes: 0,
-        no: 0,
-        abstain: 0,
-        total_eligible: member_count,
-    };
-    VOTE_COUNTS.save(deps.storage, proposal_id, &vote_count)?;
-
-    // 更新提案计数器
-    PROPOSAL_COUNTER.save(deps.storage, &(proposal_id + 1))?;
+        },
+    )?;
+    let proposal_id = proposal.id;
+    let voting_end = proposal.voting_end;
 
     Ok(Response::new()
         .add_attribute("method", "update_dao_config")
@@ -1457,8 +3434,559 @@ pub fn execute_update_dao_config(
             "new_approval_threshold",
             new_config.approval_threshold.to_string(),
         )
+        .add_attribute("old_quorum", current_config.quorum.to_string())
+        .add_attribute("new_quorum", new_config.quorum.to_string())
         .add_attribute("old_min_members", current_config.min_members.to_string())
         .add_attribute("new_min_members", new_config.min_members.to_string())
         .add_attribute("voting_end", voting_end.to_string())
         .add_attribute("proposal_type", "config_update"))
 }
+
+/// 编辑为评审中的文章提案分配评审人，评审人需在评审期限内提交评审
+pub fn execute_assign_reviewer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    reviewer: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let review_config = DAO_CONFIG
+        .load(deps.storage)?
+        .review_config
+        .ok_or(ContractError::NotEditor {})?;
+    if !review_config.editors.contains(&info.sender) {
+        return Err(ContractError::NotEditor {});
+    }
+
+    let mut proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    if proposal.status != ProposalStatus::UnderReview {
+        return Err(ContractError::ProposalNotUnderReview {});
+    }
+    if is_review_expired(&env, &proposal) {
+        return Err(ContractError::ReviewDeadlinePassed {});
+    }
+
+    let reviewer = deps.api.addr_validate(&reviewer)?;
+    if reviewer == proposal.proposer {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Proposer cannot review their own article",
+        )));
+    }
+    // 盲审中未揭示的评审人不再分配给同一篇文章
+    if REVIEW_ASSIGNMENTS.has(deps.storage, (&reviewer, proposal_id))
+        || REVIEWS.has(deps.storage, (proposal_id, &reviewer))
+        || REVIEW_COMMITMENTS.has(deps.storage, (proposal_id, &reviewer))
+        || REVIEW_NO_SHOWS.has(deps.storage, (proposal_id, &reviewer))
+    {
+        return Err(ContractError::ReviewerAlreadyAssigned {});
+    }
+
+    let deadline = env.block.time.seconds() + review_config.review_period;
+    REVIEW_ASSIGNMENTS.save(
+        deps.storage,
+        (&reviewer, proposal_id),
+        &ReviewAssignment {
+            proposal_id,
+            reviewer: reviewer.clone(),
+            assigned_by: info.sender.clone(),
+            deadline,
+        },
+    )?;
+
+    // 评审阶段延长到最后一名评审人的截止时间，评审赏金在此之前同样不能退还
+    if proposal
+        .review_deadline
+        .is_some_and(|review_deadline| deadline > review_deadline)
+    {
+        proposal.review_deadline = Some(deadline);
+    }
+    if let Some(bounty) = proposal.review_bounty.as_mut() {
+        if deadline > bounty.deadline {
+            bounty.deadline = deadline;
+        }
+    }
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "assign_reviewer")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("editor", info.sender)
+        .add_attribute("reviewer", reviewer)
+        .add_attribute("deadline", deadline.to_string()))
+}
+
+/// 盲审时评审人在截止时间前提交评审承诺
+/// 承诺数量达到要求后提案进入揭示期，评审人在揭示期内通过 `SubmitReview` 公开评审
+pub fn execute_commit_review(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    commitment: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let mut proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    if proposal.status != ProposalStatus::UnderReview {
+        return Err(ContractError::ProposalNotUnderReview {});
+    }
+    let review_config = DAO_CONFIG
+        .load(deps.storage)?
+        .review_config
+        .filter(|config| config.reveal_period > 0)
+        .ok_or(ContractError::CommitRevealDisabled {})?;
+
+    let assignment = REVIEW_ASSIGNMENTS
+        .may_load(deps.storage, (&info.sender, proposal_id))?
+        .ok_or(ContractError::NotAssignedReviewer {})?;
+    if env.block.time.seconds() > assignment.deadline {
+        return Err(ContractError::ReviewDeadlinePassed {});
+    }
+
+    let commitment = normalize_commitment(&commitment)?;
+    REVIEW_COMMITMENTS.save(deps.storage, (proposal_id, &info.sender), &commitment)?;
+    REVIEW_ASSIGNMENTS.remove(deps.storage, (&info.sender, proposal_id));
+
+    let mut response = Response::new()
+        .add_attribute("method", "commit_review")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("reviewer", info.sender);
+
+    // 已公开的评审和待揭示的承诺合计达到要求后进入揭示期
+    let commitment_count = REVIEW_COMMITMENTS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .count() as u64;
+    let review_count = REVIEWS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .count() as u64;
+    if commitment_count + review_count >= review_config.required_reviews {
+        validate_proposal_status_transition(&proposal.status, &ProposalStatus::ReviewReveal)?;
+        proposal.status = ProposalStatus::ReviewReveal;
+        proposal.reveal_end = Some(env.block.time.seconds() + review_config.reveal_period);
+        PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+        response = response
+            .add_attribute("action", "reveal_started")
+            .add_attribute(
+                "reveal_end",
+                proposal.reveal_end.unwrap_or_default().to_string(),
+            );
+    }
+
+    Ok(response.add_attribute("commitment_count", commitment_count.to_string()))
+}
+
+/// 评审人提交评审
+/// 公开评审时评审数量达到要求后提案进入投票，投票期从此时开始计算
+/// 盲审时只能在揭示期内提交，且内容需与之前的承诺一致
+#[allow(clippy::too_many_arguments)]
+pub fn execute_submit_review(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    score: u8,
+    recommendation: ReviewRecommendation,
+    report_ipfs_hash: String,
+    salt: Option<String>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let mut proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    let dao_config = DAO_CONFIG.load(deps.storage)?;
+    let revealing = proposal.status == ProposalStatus::ReviewReveal;
+    if revealing {
+        ensure_in_reveal_phase(&env, &proposal, ProposalStatus::ReviewReveal)?;
+        let commitment = REVIEW_COMMITMENTS
+            .may_load(deps.storage, (proposal_id, &info.sender))?
+            .ok_or(ContractError::NoCommitment {})?;
+        let salt = salt.ok_or(ContractError::CommitmentRequired {})?;
+        if review_commitment(
+            proposal_id,
+            &info.sender,
+            score,
+            &recommendation,
+            &report_ipfs_hash,
+            &salt,
+        ) != commitment
+        {
+            return Err(ContractError::CommitmentMismatch {});
+        }
+    } else {
+        if proposal.status != ProposalStatus::UnderReview {
+            return Err(ContractError::ProposalNotUnderReview {});
+        }
+        // 盲审时需要先提交承诺
+        if dao_config
+            .review_config
+            .as_ref()
+            .is_some_and(|config| config.reveal_period > 0)
+        {
+            return Err(ContractError::CommitmentRequired {});
+        }
+        let assignment = REVIEW_ASSIGNMENTS
+            .may_load(deps.storage, (&info.sender, proposal_id))?
+            .ok_or(ContractError::NotAssignedReviewer {})?;
+        if env.block.time.seconds() > assignment.deadline {
+            return Err(ContractError::ReviewDeadlinePassed {});
+        }
+    }
+    if score == 0 || score > MAX_REVIEW_SCORE {
+        return Err(ContractError::InvalidReviewScore {});
+    }
+    if report_ipfs_hash.trim().is_empty() {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Review report IPFS hash cannot be empty",
+        )));
+    }
+
+    REVIEWS.save(
+        deps.storage,
+        (proposal_id, &info.sender),
+        &Review {
+            reviewer: info.sender.clone(),
+            score,
+            recommendation: recommendation.clone(),
+            report_ipfs_hash,
+            submitted_at: env.block.time.seconds(),
+            accepted: false,
+        },
+    )?;
+    REVIEW_ASSIGNMENTS.remove(deps.storage, (&info.sender, proposal_id));
+    REVIEW_COMMITMENTS.remove(deps.storage, (proposal_id, &info.sender));
+
+    let mut response = Response::new()
+        .add_attribute("method", "submit_review")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("reviewer", info.sender)
+        .add_attribute("score", score.to_string())
+        .add_attribute("recommendation", format!("{:?}", recommendation));
+
+    let review_count = REVIEWS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .count() as u64;
+    if revealing {
+        // 所有承诺都已揭示时不必等到揭示期结束
+        let pending = REVIEW_COMMITMENTS
+            .prefix(proposal_id)
+            .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .next()
+            .is_some();
+        if !pending {
+            close_review_reveal(deps.storage, &env, &mut proposal)?;
+            response = response.add_attribute("proposal_status", format!("{:?}", proposal.status));
+        }
+        return Ok(response.add_attribute("review_count", review_count.to_string()));
+    }
+
+    // 评审期间关闭了同行评审时，任何一份评审都可让提案进入投票
+    let required_reviews = dao_config
+        .review_config
+        .as_ref()
+        .map_or(0, |config| config.required_reviews);
+    if review_count >= required_reviews {
+        start_voting(deps.storage, &env, &dao_config, &mut proposal)?;
+        PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+        response = response
+            .add_attribute("action", "voting_started")
+            .add_attribute("voting_end", proposal.voting_end.to_string());
+    }
+
+    Ok(response.add_attribute("review_count", review_count.to_string()))
+}
+
+/// 编辑或 DAO 接受评审，评审赏金结算前都可以接受
+/// DAO 通过自定义消息提案调用合约自身时，调用者为合约地址
+pub fn execute_accept_review(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    reviewer: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let is_editor = DAO_CONFIG
+        .load(deps.storage)?
+        .review_config
+        .is_some_and(|config| config.editors.contains(&info.sender));
+    if !is_editor && info.sender != env.contract.address {
+        return Err(ContractError::NotEditor {});
+    }
+
+    let proposal = ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    if proposal
+        .review_bounty
+        .as_ref()
+        .is_some_and(|bounty| bounty.status == ReviewBountyStatus::Settled)
+    {
+        return Err(ContractError::ReviewBountySettled {});
+    }
+
+    let reviewer = deps.api.addr_validate(&reviewer)?;
+    let mut review = REVIEWS
+        .may_load(deps.storage, (proposal_id, &reviewer))?
+        .ok_or(ContractError::ReviewNotFound {})?;
+    if review.accepted {
+        return Err(ContractError::ReviewAlreadyAccepted {});
+    }
+    review.accepted = true;
+    REVIEWS.save(deps.storage, (proposal_id, &reviewer), &review)?;
+    // 只有被接受的评审计入声誉
+    record_reputation(
+        deps.storage,
+        &env,
+        &reviewer,
+        ReputationEvent::ReviewCompleted,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "accept_review")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("accepted_by", info.sender)
+        .add_attribute("reviewer", reviewer))
+}
+
+/// 结算评审赏金
+/// 每个评审名额分得相同份额，被接受的评审人各得一份，未被领取的名额和零头退还提案者
+pub fn execute_settle_review_bounty(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    ensure_proposal_exists(deps.as_ref(), proposal_id)?;
+    let status = check_and_update_proposal_status(deps.branch(), env.clone(), proposal_id)?;
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    let bounty = proposal
+        .review_bounty
+        .as_mut()
+        .ok_or(ContractError::NoReviewBounty {})?;
+    if bounty.status == ReviewBountyStatus::Settled {
+        return Err(ContractError::ReviewBountySettled {});
+    }
+
+    // 评审和投票进行中不能结算，评审截止时间已过仍未完成评审的除外
+    let review_overdue =
+        status == ProposalStatus::UnderReview && env.block.time.seconds() > bounty.deadline;
+    if !review_overdue
+        && matches!(
+            status,
+            ProposalStatus::UnderReview
+                | ProposalStatus::ReviewReveal
+                | ProposalStatus::Active
+                | ProposalStatus::VoteReveal
+        )
+    {
+        return Err(ContractError::ReviewBountyLocked {});
+    }
+
+    let accepted: Vec<Addr> = REVIEWS
+        .prefix(proposal_id)
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((reviewer, review)) if review.accepted => Some(Ok(reviewer)),
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        })
+        .collect::<StdResult<_>>()?;
+    let slots = bounty.reviewer_slots.max(accepted.len() as u64).max(1);
+    let share = bounty.amount.amount / Uint128::from(slots);
+    let mut messages = vec![];
+    let mut paid = Uint128::zero();
+    if !share.is_zero() {
+        for reviewer in &accepted {
+            messages.push(BankMsg::Send {
+                to_address: reviewer.to_string(),
+                amount: vec![Coin {
+                    denom: bounty.amount.denom.clone(),
+                    amount: share,
+                }],
+            });
+            paid += share;
+        }
+    }
+    let refund = bounty.amount.amount - paid;
+    if !refund.is_zero() {
+        messages.push(BankMsg::Send {
+            to_address: proposal.proposer.to_string(),
+            amount: vec![Coin {
+                denom: bounty.amount.denom.clone(),
+                amount: refund,
+            }],
+        });
+    }
+    bounty.status = ReviewBountyStatus::Settled;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("method", "settle_review_bounty")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("accepted_reviews", accepted.len().to_string())
+        .add_attribute("reviewer_share", share.to_string())
+        .add_attribute("refunded", refund.to_string()))
+}
+
+/// 结束盲审揭示期：未揭示的承诺记为缺席
+/// 公开的评审数量达到要求时进入投票，否则回到评审阶段等待编辑补充评审人
+fn close_review_reveal(
+    storage: &mut dyn cosmwasm_std::Storage,
+    env: &Env,
+    proposal: &mut Proposal,
+) -> Result<(), ContractError> {
+    let no_shows: Vec<Addr> = REVIEW_COMMITMENTS
+        .prefix(proposal.id)
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for reviewer in &no_shows {
+        REVIEW_COMMITMENTS.remove(storage, (proposal.id, reviewer));
+        REVIEW_NO_SHOWS.save(storage, (proposal.id, reviewer), &Empty {})?;
+    }
+
+    let dao_config = DAO_CONFIG.load(storage)?;
+    let required_reviews = dao_config
+        .review_config
+        .as_ref()
+        .map_or(0, |config| config.required_reviews);
+    let review_count = REVIEWS
+        .prefix(proposal.id)
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .count() as u64;
+    if review_count >= required_reviews {
+        start_voting(storage, env, &dao_config, proposal)?;
+    } else {
+        // 回到评审阶段，编辑需在新的评审期限内重新分配评审人
+        validate_proposal_status_transition(&proposal.status, &ProposalStatus::UnderReview)?;
+        proposal.status = ProposalStatus::UnderReview;
+        let review_period = dao_config
+            .review_config
+            .as_ref()
+            .map_or(0, |config| config.review_period);
+        let review_deadline = env.block.time.seconds() + review_period;
+        proposal.review_deadline = Some(review_deadline);
+        // 评审赏金同样在新的评审期限之前不能退还
+        if let Some(bounty) = proposal.review_bounty.as_mut() {
+            if review_deadline > bounty.deadline {
+                bounty.deadline = review_deadline;
+            }
+        }
+    }
+    proposal.reveal_end = None;
+    PROPOSALS.save(storage, proposal.id, proposal)?;
+    Ok(())
+}
+
+/// 提交修改接受的支付代币列表的提案
+pub fn execute_update_accepted_denoms(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denoms: Vec<String>,
+) -> Result<Response, ContractError> {
+    // 验证提案者是 DAO 成员
+    ensure_dao_member(deps.as_ref(), &info.sender)?;
+
+    validate_denoms(&denoms)?;
+    ensure_denoms_removable(deps.as_ref(), &denoms)?;
+
+    let current_config = PAYMENT_CONFIG.load(deps.storage)?;
+    let description = format!(
+        "Update accepted denominations: [{}] -> [{}]",
+        current_config.accepted_denoms.join(", "),
+        denoms.join(", ")
+    );
+
+    let proposal = create_proposal(
+        deps,
+        &env,
+        &info,
+        ProposalType::UpdateDenoms,
+        "Accepted Denominations Update".to_string(),
+        description,
+        ExecutionData::DenomsUpdate {
+            accepted_denoms: denoms.clone(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_accepted_denoms")
+        .add_attribute("proposal_id", proposal.id.to_string())
+        .add_attribute("proposer", info.sender.to_string())
+        .add_attribute("new_accepted_denoms", denoms.join(","))
+        .add_attribute("voting_end", proposal.voting_end.to_string())
+        .add_attribute("proposal_type", "denoms_update"))
+}
+
+/// 提交修改费用配置的提案
+pub fn execute_update_fees(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base_citation_fee: Option<Coin>,
+    citation_dao_share_bps: Option<u64>,
+    access_protocol_fee_bps: Option<u64>,
+) -> Result<Response, ContractError> {
+    // 验证提案者是 DAO 成员
+    ensure_dao_member(deps.as_ref(), &info.sender)?;
+
+    // 创建新配置，使用提供的值或保持当前值
+    let current_config = FEE_CONFIG.load(deps.storage)?;
+    let new_config = FeeConfig {
+        base_citation_fee: base_citation_fee
+            .unwrap_or_else(|| current_config.base_citation_fee.clone()),
+        citation_dao_share_bps: citation_dao_share_bps
+            .unwrap_or(current_config.citation_dao_share_bps),
+        access_protocol_fee_bps: access_protocol_fee_bps
+            .unwrap_or(current_config.access_protocol_fee_bps),
+    };
+    validate_fee_config(&new_config)?;
+    ensure_accepted_denom(deps.as_ref(), &new_config.base_citation_fee.denom)?;
+
+    let description = format!(
+        "Update fees: citation fee {} -> {}, citation DAO share {} -> {} bps, access protocol fee {} -> {} bps",
+        current_config.base_citation_fee,
+        new_config.base_citation_fee,
+        current_config.citation_dao_share_bps,
+        new_config.citation_dao_share_bps,
+        current_config.access_protocol_fee_bps,
+        new_config.access_protocol_fee_bps
+    );
+
+    let proposal = create_proposal(
+        deps,
+        &env,
+        &info,
+        ProposalType::UpdateFees,
+        "Fee Schedule Update".to_string(),
+        description,
+        ExecutionData::FeesUpdate {
+            new_config: new_config.clone(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_fees")
+        .add_attribute("proposal_id", proposal.id.to_string())
+        .add_attribute("proposer", info.sender.to_string())
+        .add_attribute(
+            "new_base_citation_fee",
+            new_config.base_citation_fee.to_string(),
+        )
+        .add_attribute(
+            "new_citation_dao_share_bps",
+            new_config.citation_dao_share_bps.to_string(),
+        )
+        .add_attribute(
+            "new_access_protocol_fee_bps",
+            new_config.access_protocol_fee_bps.to_string(),
+        )
+        .add_attribute("voting_end", proposal.voting_end.to_string())
+        .add_attribute("proposal_type", "fees_update"))
+}
@@ -1,10 +1,13 @@
+use crate::contracts::{CW2_CONTRACT_NAME, CW2_CONTRACT_VERSION};
 use crate::error::ContractError;
-use crate::msg::{DaoConfig, InstantiateMsg};
+use crate::helpers::{default_reputation_config, validate_denoms, DEFAULT_QUORUM};
+use crate::msg::{DaoConfig, FeeConfig, InstantiateMsg, PaymentConfig, VotingWeightSource};
 use crate::state::{
-    BASE_CITATION_FEE, CONTRACT_NAME, CONTRACT_OWNER, CONTRACT_SYMBOL, DAO_CONFIG, DAO_MEMBERS,
-    PROPOSAL_COUNTER, TOKEN_COUNT, TOKEN_ID_COUNTER,
+    CONTRACT_NAME, CONTRACT_OWNER, CONTRACT_SYMBOL, DAO_CONFIG, DAO_MEMBERS, FEE_CONFIG,
+    PAYMENT_CONFIG, PROPOSAL_COUNTER, TOKEN_COUNT, TOKEN_ID_COUNTER,
 };
-use cosmwasm_std::{entry_point, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{entry_point, Coin, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw2::set_contract_version;
 
 #[entry_point]
 pub fn instantiate(
@@ -15,23 +18,54 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     let owner = deps.api.addr_validate(&msg.owner)?;
 
+    set_contract_version(deps.storage, CW2_CONTRACT_NAME, CW2_CONTRACT_VERSION)?;
+
     CONTRACT_NAME.save(deps.storage, &msg.name)?;
     CONTRACT_SYMBOL.save(deps.storage, &msg.symbol)?;
     CONTRACT_OWNER.save(deps.storage, &owner)?;
     TOKEN_ID_COUNTER.save(deps.storage, &0u64)?;
     TOKEN_COUNT.save(deps.storage, &0u64)?;
 
-    BASE_CITATION_FEE.save(deps.storage, &Uint128::new(100_000))?; // 0.1 token
+    // 保存接受的支付代币
+    validate_denoms(&msg.accepted_denoms)?;
+    PAYMENT_CONFIG.save(
+        deps.storage,
+        &PaymentConfig {
+            accepted_denoms: msg.accepted_denoms.clone(),
+        },
+    )?;
+
+    // 默认费用：引用费用以第一个接受的代币计价，95% 给作者、5% 给 DAO，访问费用全部给数据所有者
+    FEE_CONFIG.save(
+        deps.storage,
+        &FeeConfig {
+            base_citation_fee: Coin {
+                denom: msg.accepted_denoms[0].clone(),
+                amount: Uint128::new(100_000), // 0.1 token
+            },
+            citation_dao_share_bps: 500,
+            access_protocol_fee_bps: 0,
+        },
+    )?;
 
     // 初始化 DAO
     // 将合约创建者设置为第一个 DAO 成员
-    DAO_MEMBERS.save(deps.storage, owner.as_str(), &true)?;
+    DAO_MEMBERS.save(deps.storage, owner.as_str(), &1)?;
 
     // 初始化默认的 DAO 配置参数
     let dao_config = DaoConfig {
         voting_period: 604800,  // 7 天 (7 * 24 * 60 * 60 秒)
         approval_threshold: 51, // 51% 通过阈值
+        quorum: DEFAULT_QUORUM, // 33% 法定人数
         min_members: 1,         // 最小成员数量为 1
+        proposal_deposit: None, // 默认无需提案押金
+        slash_spam_deposits: false,
+        execution_delay: 0,    // 默认通过后立即执行
+        veto_council: None,    // 默认没有否决委员会
+        review_config: None,   // 默认文章提案直接进入投票
+        vote_reveal_period: 0, // 默认公开投票
+        reputation_config: default_reputation_config(),
+        voting_weight_source: VotingWeightSource::MemberWeight, // 默认只按成员权重计票
     };
     DAO_CONFIG.save(deps.storage, &dao_config)?;
 
@@ -43,6 +77,7 @@ pub fn instantiate(
         .add_attribute("name", &msg.name)
         .add_attribute("symbol", &msg.symbol)
         .add_attribute("owner", owner.as_str())
+        .add_attribute("accepted_denoms", msg.accepted_denoms.join(","))
         .add_attribute("dao_initialized", "true")
         .add_attribute("first_dao_member", owner.as_str())
         .add_attribute("base_citation_fee", "1000000"))
@@ -0,0 +1,830 @@
+use crate::contracts::{CW2_CONTRACT_NAME, CW2_CONTRACT_VERSION};
+use crate::error::ContractError;
+use crate::msg::MigrateMsg;
+use cosmwasm_std::{entry_point, DepsMut, Env, Response, StdError};
+use cw2::{set_contract_version, CONTRACT};
+use semver::Version;
+
+/// 未记录 cw2 版本信息的旧合约视为 0.1.0
+const LEGACY_VERSION: &str = "0.1.0";
+
+#[entry_point]
+pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    // 检查已存储的合约名称和版本
+    let from_version = match CONTRACT.may_load(deps.storage)? {
+        Some(info) => {
+            if info.contract != CW2_CONTRACT_NAME {
+                return Err(ContractError::WrongContract {
+                    contract: info.contract,
+                });
+            }
+            info.version
+        }
+        None => LEGACY_VERSION.to_string(),
+    };
+
+    let from = parse_version(&from_version)?;
+    let to = parse_version(CW2_CONTRACT_VERSION)?;
+
+    // 不允许降级
+    if from > to {
+        return Err(ContractError::CannotDowngrade {
+            from: from_version,
+            to: CW2_CONTRACT_VERSION.to_string(),
+        });
+    }
+
+    let mut response = Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", &from_version)
+        .add_attribute("to_version", CW2_CONTRACT_VERSION);
+
+    // 按版本顺序执行状态迁移
+    if from < Version::new(0, 2, 0) {
+        v0_1::migrate_state(deps.branch(), &env, &msg)?;
+        response = response.add_attribute("state_migration", "v0_1");
+    }
+
+    set_contract_version(deps.storage, CW2_CONTRACT_NAME, CW2_CONTRACT_VERSION)?;
+
+    Ok(response)
+}
+
+fn parse_version(version: &str) -> Result<Version, ContractError> {
+    Version::parse(version).map_err(|e| {
+        ContractError::Std(StdError::generic_err(format!(
+            "Invalid contract version {}: {}",
+            version, e
+        )))
+    })
+}
+
+/// 0.1.x 版本的状态结构及其到当前版本的迁移
+mod v0_1 {
+    use crate::error::ContractError;
+    use crate::msg::{
+        DaoConfig, DataItem, ExecutionData, Expiration, FeeConfig, MemberAction, MigrateMsg,
+        PaymentConfig, Proposal, ProposalStatus, ProposalType, VoteCount, VotingWeightSource,
+    };
+    use crate::state::{
+        APPROVED_PAPERS, AUTHORIZED_USERS, DAO_CONFIG, DAO_MEMBERS, DATA_ITEMS, DOI_INDEX,
+        FEE_CONFIG, OPERATOR_APPROVALS, PAPER_DOIS, PAYMENT_CONFIG, PROPOSALS, PROPOSAL_POWER,
+        PROPOSAL_VOTERS, TOKEN_APPROVALS, TOKEN_OWNERS, VOTE_COUNTS,
+    };
+    use cosmwasm_std::{Addr, Coin, DepsMut, Empty, Env, Order, StdResult, Uint128};
+    use cw_storage_plus::{Item, Map};
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
+    /// 旧版本硬编码的支付代币
+    pub const LEGACY_DENOM: &str = "inj";
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub struct LegacyDataItem {
+        pub owner: Addr,
+        pub ipfs_hash: String,
+        pub price: Uint128,
+        pub is_public: bool,
+        pub total_earned: Uint128,
+        pub created_at: u64,
+        pub last_updated: u64,
+        pub metadata_uri: String,
+        pub is_frozen: bool,
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub struct LegacyDaoConfig {
+        pub voting_period: u64,
+        pub approval_threshold: u64,
+        pub min_members: u64,
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub struct LegacyProposal {
+        pub id: u64,
+        pub proposer: Addr,
+        pub proposal_type: ProposalType,
+        pub title: String,
+        pub description: String,
+        pub created_at: u64,
+        pub voting_end: u64,
+        pub status: ProposalStatus,
+        pub execution_data: Option<LegacyExecutionData>,
+    }
+
+    /// 旧版本的提案执行数据，配置更新只包含旧版本的三个配置字段
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub enum LegacyExecutionData {
+        ArticlePublication {
+            ipfs_hash: String,
+            doi: String,
+            metadata_uri: String,
+        },
+        MemberChange {
+            member_address: String,
+            action: MemberAction,
+        },
+        ConfigUpdate {
+            new_config: LegacyDaoConfig,
+        },
+    }
+
+    impl From<LegacyExecutionData> for ExecutionData {
+        fn from(data: LegacyExecutionData) -> Self {
+            match data {
+                LegacyExecutionData::ArticlePublication {
+                    ipfs_hash,
+                    doi,
+                    metadata_uri,
+                } => ExecutionData::ArticlePublication {
+                    ipfs_hash,
+                    doi,
+                    metadata_uri,
+                },
+                LegacyExecutionData::MemberChange {
+                    member_address,
+                    action,
+                } => ExecutionData::MemberChange {
+                    member_address,
+                    action,
+                    weight: None,
+                },
+                LegacyExecutionData::ConfigUpdate { new_config } => ExecutionData::ConfigUpdate {
+                    new_config: new_config.into(),
+                },
+            }
+        }
+    }
+
+    /// DAO 配置新增的字段使用旧合约的行为：默认法定人数，不收押金，通过后立即执行
+    impl From<LegacyDaoConfig> for DaoConfig {
+        fn from(config: LegacyDaoConfig) -> Self {
+            DaoConfig {
+                voting_period: config.voting_period,
+                approval_threshold: config.approval_threshold,
+                min_members: config.min_members,
+                quorum: crate::helpers::DEFAULT_QUORUM,
+                proposal_deposit: None,
+                slash_spam_deposits: false,
+                execution_delay: 0,
+                veto_council: None,
+                review_config: None,
+                vote_reveal_period: 0,
+                reputation_config: crate::helpers::default_reputation_config(),
+                voting_weight_source: VotingWeightSource::MemberWeight,
+            }
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    pub struct LegacyVoteCount {
+        pub yes: u64,
+        pub no: u64,
+        pub abstain: u64,
+        pub total_eligible: u64,
+    }
+
+    /// 旧版本硬编码的引用费用分成：5% 给 DAO
+    pub const LEGACY_CITATION_DAO_SHARE_BPS: u64 = 500;
+
+    pub const LEGACY_BASE_CITATION_FEE: Item<Uint128> = Item::new("base_citation_fee");
+    pub const LEGACY_DAO_MEMBERS: Map<&str, bool> = Map::new("dao_members");
+    pub const LEGACY_DAO_CONFIG: Item<LegacyDaoConfig> = Item::new("dao_config");
+    pub const LEGACY_PROPOSALS: Map<u64, LegacyProposal> = Map::new("proposals");
+    pub const LEGACY_VOTE_COUNTS: Map<u64, LegacyVoteCount> = Map::new("vote_counts");
+    pub const LEGACY_TOKEN_OWNERS: Map<&str, Addr> = Map::new("token_owners");
+    pub const LEGACY_DATA_ITEMS: Map<&str, LegacyDataItem> = Map::new("data_items");
+    pub const LEGACY_AUTHORIZED_USERS: Map<&str, Vec<Addr>> = Map::new("authorized_users");
+    pub const LEGACY_TOKEN_APPROVALS: Map<&str, Addr> = Map::new("token_approvals");
+    pub const LEGACY_OPERATOR_APPROVALS: Map<(&str, &str), bool> = Map::new("operator_approvals");
+
+    pub fn migrate_state(deps: DepsMut, env: &Env, msg: &MigrateMsg) -> Result<(), ContractError> {
+        // 旧版本没有支付配置
+        if !PAYMENT_CONFIG.exists(deps.storage) {
+            let accepted_denoms = msg
+                .accepted_denoms
+                .clone()
+                .unwrap_or_else(|| vec![LEGACY_DENOM.to_string()]);
+            crate::helpers::validate_denoms(&accepted_denoms)?;
+            PAYMENT_CONFIG.save(deps.storage, &PaymentConfig { accepted_denoms })?;
+        }
+
+        // 引用费用并入费用配置并以第一个接受的代币计价，分成比例沿用旧版本的硬编码值，访问费用不收协议分成
+        if let Some(base_citation_fee) = LEGACY_BASE_CITATION_FEE.may_load(deps.storage)? {
+            let denom = PAYMENT_CONFIG.load(deps.storage)?.accepted_denoms[0].clone();
+            FEE_CONFIG.save(
+                deps.storage,
+                &FeeConfig {
+                    base_citation_fee: Coin {
+                        denom,
+                        amount: base_citation_fee,
+                    },
+                    citation_dao_share_bps: LEGACY_CITATION_DAO_SHARE_BPS,
+                    access_protocol_fee_bps: 0,
+                },
+            )?;
+            LEGACY_BASE_CITATION_FEE.remove(deps.storage);
+        }
+
+        // DAO 配置新增法定人数、提案押金和执行时间锁等设置
+        let config = LEGACY_DAO_CONFIG.load(deps.storage)?;
+        DAO_CONFIG.save(deps.storage, &config.into())?;
+
+        // 成员由是否为成员的标记改为投票权重，现有成员权重为 1
+        let legacy_members: Vec<(String, bool)> = LEGACY_DAO_MEMBERS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        let mut members = vec![];
+        for (member, is_member) in legacy_members {
+            if is_member {
+                DAO_MEMBERS.save(deps.storage, &member, &1)?;
+                members.push(member);
+            } else {
+                DAO_MEMBERS.remove(deps.storage, &member);
+            }
+        }
+
+        // 提案新增押金记录和投票者快照，投票统计新增 NoWithVeto 计数
+        // 旧提案没有快照，进行中的提案以迁移时的 DAO 成员作为投票者，旧版本没有委托
+        let proposals: Vec<(u64, LegacyProposal)> = LEGACY_PROPOSALS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for (id, proposal) in proposals {
+            let proposal = Proposal {
+                id: proposal.id,
+                proposer: proposal.proposer,
+                proposal_type: proposal.proposal_type,
+                title: proposal.title,
+                description: proposal.description,
+                created_at: proposal.created_at,
+                voting_end: proposal.voting_end,
+                status: proposal.status,
+                execution_data: proposal.execution_data.map(Into::into),
+                deposit: None,
+                snapshot_height: env.block.height,
+                executable_at: None,
+                execution_deadline: None,
+                review_deadline: None,
+                vote_reveal_period: 0,
+                reveal_end: None,
+                review_bounty: None,
+            };
+            if proposal.status == ProposalStatus::Active {
+                for member in &members {
+                    PROPOSAL_VOTERS.save(deps.storage, (id, member), &1)?;
+                    PROPOSAL_POWER.save(deps.storage, (id, member), &1)?;
+                }
+            }
+            PROPOSALS.save(deps.storage, id, &proposal)?;
+        }
+
+        let counts: Vec<(u64, LegacyVoteCount)> = LEGACY_VOTE_COUNTS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for (id, count) in counts {
+            // 进行中的提案按重建的快照计算总投票权，与投票授权使用的快照保持一致
+            let total_eligible =
+                if PROPOSALS.load(deps.storage, id)?.status == ProposalStatus::Active {
+                    PROPOSAL_VOTERS
+                        .prefix(id)
+                        .range(deps.storage, None, None, Order::Ascending)
+                        .map(|item| item.map(|(_, weight)| weight))
+                        .sum::<StdResult<u64>>()?
+                } else {
+                    count.total_eligible
+                };
+            let vote_count = VoteCount {
+                yes: count.yes,
+                no: count.no,
+                no_with_veto: 0,
+                abstain: count.abstain,
+                total_eligible,
+            };
+            VOTE_COUNTS.save(deps.storage, id, &vote_count)?;
+        }
+
+        // 所有权存储增加按所有者的索引，主键数据不变，只需补建索引
+        let owners: Vec<(String, Addr)> = LEGACY_TOKEN_OWNERS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for (token_id, owner) in owners {
+            TOKEN_OWNERS.replace(deps.storage, &token_id, Some(&owner), None)?;
+        }
+
+        // 建立 DOI 反向索引，已存储的 DOI 统一规范化
+        // 旧版本未强制 DOI 唯一，重复的 DOI 只索引 token ID 最小的论文
+        let dois: Vec<(String, String)> = PAPER_DOIS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for (token_id, doi) in dois {
+            let doi = crate::helpers::normalize_doi(&doi).unwrap_or(doi);
+            PAPER_DOIS.save(deps.storage, &token_id, &doi)?;
+            if !DOI_INDEX.has(deps.storage, &doi) {
+                DOI_INDEX.save(deps.storage, &doi, &token_id)?;
+            }
+        }
+
+        // 声誉只计入 DAO 批准发表的论文，按已执行的文章发布提案中的 DOI 找回对应论文
+        let published: Vec<(u64, Proposal)> = PROPOSALS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for (id, proposal) in published {
+            if proposal.status != ProposalStatus::Executed {
+                continue;
+            }
+            if let Some(ExecutionData::ArticlePublication { doi, .. }) = proposal.execution_data {
+                let Ok(doi) = crate::helpers::normalize_doi(&doi) else {
+                    continue;
+                };
+                if let Some(token_id) = DOI_INDEX.may_load(deps.storage, &doi)? {
+                    APPROVED_PAPERS.save(deps.storage, &token_id, &id)?;
+                }
+            }
+        }
+
+        // DataItem 新增计价代币和退役字段，旧数据固定使用 inj 计价
+        let items: Vec<(String, LegacyDataItem)> = LEGACY_DATA_ITEMS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for (token_id, item) in items {
+            let data_item = DataItem {
+                owner: item.owner,
+                ipfs_hash: item.ipfs_hash,
+                price: item.price,
+                denom: LEGACY_DENOM.to_string(),
+                is_public: item.is_public,
+                total_earned: item.total_earned,
+                created_at: item.created_at,
+                last_updated: item.last_updated,
+                metadata_uri: item.metadata_uri,
+                is_frozen: item.is_frozen,
+                is_retired: false,
+            };
+            DATA_ITEMS.save(deps.storage, &token_id, &data_item)?;
+            crate::helpers::update_listed_denoms(deps.storage, &data_item, true)?;
+        }
+
+        // 授权用户列表由 Vec 改为按 (token_id, 地址) 逐条存储
+        let lists: Vec<(String, Vec<Addr>)> = LEGACY_AUTHORIZED_USERS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for (token_id, users) in lists {
+            for user in users {
+                AUTHORIZED_USERS.save(deps.storage, (&token_id, &user), &Empty {})?;
+            }
+            LEGACY_AUTHORIZED_USERS.remove(deps.storage, &token_id);
+        }
+
+        // 批准改为 cw721 风格：每个 token 可有多个带过期时间的批准，旧批准永不过期
+        let approvals: Vec<(String, Addr)> = LEGACY_TOKEN_APPROVALS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for (token_id, spender) in approvals {
+            TOKEN_APPROVALS.save(
+                deps.storage,
+                (&token_id, spender.as_str()),
+                &Expiration::Never {},
+            )?;
+            LEGACY_TOKEN_APPROVALS.remove(deps.storage, &token_id);
+        }
+
+        let operators: Vec<((String, String), bool)> = LEGACY_OPERATOR_APPROVALS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        for ((owner, operator), approved) in operators {
+            if approved {
+                OPERATOR_APPROVALS.save(
+                    deps.storage,
+                    (&owner, &operator),
+                    &Expiration::Never {},
+                )?;
+            }
+            LEGACY_OPERATOR_APPROVALS.remove(deps.storage, (&owner, &operator));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::v0_1::*;
+    use super::*;
+    use crate::contracts::query::query;
+    use crate::msg::{
+        AccessLevel, Approval, DaoConfig, DaoConfigResponse, DataItem, DataVersion, ExecutionData,
+        Expiration, FeeConfigResponse, MemberAction, OperatorResponse, OwnerOfResponse,
+        PaymentConfigResponse, ProposalResponse, ProposalStatus, ProposalType, QueryMsg,
+        TokenInfoResponse, TokensResponse, VoteCountResponse, VotingWeightSource,
+    };
+    use crate::state::{
+        ACCESS_CONTROLS, APPROVED_PAPERS, CONTRACT_NAME, CONTRACT_OWNER, CONTRACT_SYMBOL,
+        DAO_MEMBERS, DATA_VERSIONS, LISTED_DENOMS, PAPER_DOIS, PROPOSAL_COUNTER, PROPOSAL_POWER,
+        PROPOSAL_VOTERS, TOKEN_COUNT, TOKEN_ID_COUNTER,
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{coin, from_json, Addr, Storage, Uint128};
+
+    /// 按 0.1.0 版本的存储结构写入一个私有数据项和一篇论文
+    fn legacy_state(storage: &mut dyn Storage) {
+        let owner = Addr::unchecked("creator");
+        CONTRACT_NAME
+            .save(storage, &"Research Data NFT".to_string())
+            .unwrap();
+        CONTRACT_SYMBOL.save(storage, &"RDN".to_string()).unwrap();
+        CONTRACT_OWNER.save(storage, &owner).unwrap();
+        LEGACY_BASE_CITATION_FEE
+            .save(storage, &Uint128::new(100_000))
+            .unwrap();
+        LEGACY_DAO_MEMBERS
+            .save(storage, owner.as_str(), &true)
+            .unwrap();
+        LEGACY_DAO_MEMBERS.save(storage, "former", &false).unwrap();
+        LEGACY_DAO_CONFIG
+            .save(
+                storage,
+                &LegacyDaoConfig {
+                    voting_period: 604800,
+                    approval_threshold: 51,
+                    min_members: 1,
+                },
+            )
+            .unwrap();
+        LEGACY_PROPOSALS
+            .save(
+                storage,
+                0,
+                &LegacyProposal {
+                    id: 0,
+                    proposer: owner.clone(),
+                    proposal_type: ProposalType::AddMember,
+                    title: "Add reviewer".to_string(),
+                    description: "Legacy proposal".to_string(),
+                    created_at: 1,
+                    voting_end: 604801,
+                    status: ProposalStatus::Active,
+                    execution_data: Some(LegacyExecutionData::MemberChange {
+                        member_address: "reviewer".to_string(),
+                        action: MemberAction::Add,
+                    }),
+                },
+            )
+            .unwrap();
+        LEGACY_VOTE_COUNTS
+            .save(
+                storage,
+                0,
+                &LegacyVoteCount {
+                    yes: 0,
+                    no: 1,
+                    abstain: 0,
+                    total_eligible: 2,
+                },
+            )
+            .unwrap();
+        LEGACY_PROPOSALS
+            .save(
+                storage,
+                1,
+                &LegacyProposal {
+                    id: 1,
+                    proposer: owner.clone(),
+                    proposal_type: ProposalType::ArticlePublication,
+                    title: "Publish legacy paper".to_string(),
+                    description: "Legacy proposal".to_string(),
+                    created_at: 1,
+                    voting_end: 604801,
+                    status: ProposalStatus::Executed,
+                    execution_data: Some(LegacyExecutionData::ArticlePublication {
+                        ipfs_hash: "QmLegacy1".to_string(),
+                        doi: "10.1000/Legacy".to_string(),
+                        metadata_uri: "ipfs://legacy".to_string(),
+                    }),
+                },
+            )
+            .unwrap();
+        LEGACY_PROPOSALS
+            .save(
+                storage,
+                2,
+                &LegacyProposal {
+                    id: 2,
+                    proposer: owner.clone(),
+                    proposal_type: ProposalType::UpdateConfig,
+                    title: "Shorter voting".to_string(),
+                    description: "Legacy proposal".to_string(),
+                    created_at: 1,
+                    voting_end: 604801,
+                    status: ProposalStatus::Rejected,
+                    execution_data: Some(LegacyExecutionData::ConfigUpdate {
+                        new_config: LegacyDaoConfig {
+                            voting_period: 86400,
+                            approval_threshold: 60,
+                            min_members: 1,
+                        },
+                    }),
+                },
+            )
+            .unwrap();
+        PROPOSAL_COUNTER.save(storage, &3).unwrap();
+
+        for (token_id, is_public) in [("0", false), ("1", true)] {
+            LEGACY_TOKEN_OWNERS.save(storage, token_id, &owner).unwrap();
+            LEGACY_DATA_ITEMS
+                .save(
+                    storage,
+                    token_id,
+                    &LegacyDataItem {
+                        owner: owner.clone(),
+                        ipfs_hash: format!("QmLegacy{}", token_id),
+                        price: Uint128::new(500),
+                        is_public,
+                        total_earned: Uint128::zero(),
+                        created_at: 1,
+                        last_updated: 1,
+                        metadata_uri: "https://example.com/legacy.json".to_string(),
+                        is_frozen: false,
+                    },
+                )
+                .unwrap();
+            DATA_VERSIONS
+                .save(
+                    storage,
+                    token_id,
+                    &vec![DataVersion {
+                        ipfs_hash: format!("QmLegacy{}", token_id),
+                        timestamp: 1,
+                    }],
+                )
+                .unwrap();
+        }
+        PAPER_DOIS
+            .save(storage, "1", &"https://doi.org/10.1000/Legacy".to_string())
+            .unwrap();
+        TOKEN_ID_COUNTER.save(storage, &2).unwrap();
+        TOKEN_COUNT.save(storage, &2).unwrap();
+
+        ACCESS_CONTROLS
+            .save(storage, ("0", "reader"), &AccessLevel::Read)
+            .unwrap();
+        LEGACY_AUTHORIZED_USERS
+            .save(storage, "0", &vec![Addr::unchecked("reader")])
+            .unwrap();
+
+        LEGACY_TOKEN_APPROVALS
+            .save(storage, "0", &Addr::unchecked("spender"))
+            .unwrap();
+        LEGACY_OPERATOR_APPROVALS
+            .save(storage, ("creator", "operator"), &true)
+            .unwrap();
+    }
+
+    #[test]
+    fn migrate_legacy_state() {
+        let mut deps = mock_dependencies();
+        legacy_state(deps.as_mut().storage);
+
+        let msg = MigrateMsg {
+            accepted_denoms: None,
+        };
+        let res = migrate(deps.as_mut(), mock_env(), msg).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "from_version" && attr.value == LEGACY_VERSION));
+
+        // 记录了 cw2 版本
+        let version = cw2::get_contract_version(deps.as_ref().storage).unwrap();
+        assert_eq!(CW2_CONTRACT_NAME, version.contract);
+        assert_eq!(CW2_CONTRACT_VERSION, version.version);
+
+        // 旧数据项按 inj 计价
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetDataItem {
+                token_id: "0".to_string(),
+            },
+        )
+        .unwrap();
+        let data_item: DataItem = from_json(res).unwrap();
+        assert_eq!(LEGACY_DENOM, data_item.denom);
+        assert_eq!(Uint128::new(500), data_item.price);
+        assert_eq!(
+            Some(2),
+            LISTED_DENOMS
+                .may_load(deps.as_ref().storage, LEGACY_DENOM)
+                .unwrap()
+        );
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetPaymentConfig {}).unwrap();
+        let config: PaymentConfigResponse = from_json(res).unwrap();
+        assert_eq!(
+            vec![LEGACY_DENOM.to_string()],
+            config.config.accepted_denoms
+        );
+
+        // 引用费用并入费用配置
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetFeeConfig {}).unwrap();
+        let fees: FeeConfigResponse = from_json(res).unwrap();
+        assert_eq!(coin(100_000, LEGACY_DENOM), fees.config.base_citation_fee);
+        assert_eq!(
+            LEGACY_CITATION_DAO_SHARE_BPS,
+            fees.config.citation_dao_share_bps
+        );
+        assert_eq!(0, fees.config.access_protocol_fee_bps);
+        assert!(!LEGACY_BASE_CITATION_FEE.exists(deps.as_ref().storage));
+
+        // 授权用户列表已转换
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetAuthorizedUsers {
+                token_id: "0".to_string(),
+            },
+        )
+        .unwrap();
+        let users: Vec<Addr> = from_json(res).unwrap();
+        assert_eq!(vec![Addr::unchecked("reader")], users);
+        assert!(!LEGACY_AUTHORIZED_USERS.has(deps.as_ref().storage, "0"));
+
+        // 旧批准转换为永不过期的 cw721 批准
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::OwnerOf {
+                token_id: "0".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+        let owner: OwnerOfResponse = from_json(res).unwrap();
+        assert_eq!(
+            vec![Approval {
+                spender: "spender".to_string(),
+                expires: Expiration::Never {},
+            }],
+            owner.approvals
+        );
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Operator {
+                owner: "creator".to_string(),
+                operator: "operator".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+        let operator: OperatorResponse = from_json(res).unwrap();
+        assert_eq!(Expiration::Never {}, operator.approval.expires);
+
+        // 已为旧 token 补建所有者索引
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Tokens {
+                owner: "creator".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let tokens: TokensResponse = from_json(res).unwrap();
+        assert_eq!(vec!["0".to_string(), "1".to_string()], tokens.tokens);
+
+        // 旧 DOI 已规范化并可反向查找
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetPaperByDoi {
+                doi: "10.1000/LEGACY".to_string(),
+            },
+        )
+        .unwrap();
+        let paper: TokenInfoResponse = from_json(res).unwrap();
+        assert_eq!("1", paper.token_id);
+        // 经已执行的文章发布提案发表的论文计入声誉
+        assert_eq!(
+            Some(1),
+            APPROVED_PAPERS
+                .may_load(deps.as_ref().storage, "1")
+                .unwrap()
+        );
+        assert!(!APPROVED_PAPERS.has(deps.as_ref().storage, "0"));
+
+        // 旧提案和配置补充了押金字段
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetProposal { proposal_id: 0 },
+        )
+        .unwrap();
+        let proposal: ProposalResponse = from_json(res).unwrap();
+        assert_eq!(None, proposal.proposal.deposit);
+        assert_eq!(mock_env().block.height, proposal.proposal.snapshot_height);
+        assert_eq!(None, proposal.proposal.execution_deadline);
+        assert_eq!(None, proposal.proposal.review_deadline);
+        assert_eq!(0, proposal.proposal.vote_reveal_period);
+        assert_eq!(None, proposal.proposal.reveal_end);
+        assert_eq!(None, proposal.proposal.review_bounty);
+        assert_eq!(
+            Some(ExecutionData::MemberChange {
+                member_address: "reviewer".to_string(),
+                action: MemberAction::Add,
+                weight: None,
+            }),
+            proposal.proposal.execution_data
+        );
+        assert_eq!(
+            Some(1),
+            PROPOSAL_VOTERS
+                .may_load(deps.as_ref().storage, (0, "creator"))
+                .unwrap()
+        );
+
+        // 成员标记转换为权重，非成员记录被清除
+        assert_eq!(
+            Some(1),
+            DAO_MEMBERS
+                .may_load(deps.as_ref().storage, "creator")
+                .unwrap()
+        );
+        assert!(!DAO_MEMBERS.has(deps.as_ref().storage, "former"));
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetVoteCount { proposal_id: 0 },
+        )
+        .unwrap();
+        let count: VoteCountResponse = from_json(res).unwrap();
+        assert_eq!(1, count.vote_count.no);
+        assert_eq!(0, count.vote_count.no_with_veto);
+        // 总投票权来自重建的快照，而不是旧计数
+        assert_eq!(1, count.vote_count.total_eligible);
+        assert_eq!(
+            Some(1),
+            PROPOSAL_POWER
+                .may_load(deps.as_ref().storage, (0, "creator"))
+                .unwrap()
+        );
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetDaoConfig {}).unwrap();
+        let config: DaoConfigResponse = from_json(res).unwrap();
+        assert_eq!(604800, config.config.voting_period);
+        assert_eq!(None, config.config.proposal_deposit);
+        assert_eq!(0, config.config.execution_delay);
+        assert_eq!(None, config.config.review_config);
+        assert_eq!(0, config.config.vote_reveal_period);
+        assert_eq!(
+            VotingWeightSource::MemberWeight,
+            config.config.voting_weight_source
+        );
+
+        // 旧配置更新提案中的配置按相同的默认值补全新增字段
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetProposal { proposal_id: 2 },
+        )
+        .unwrap();
+        let proposal: ProposalResponse = from_json(res).unwrap();
+        let Some(ExecutionData::ConfigUpdate { new_config }) = proposal.proposal.execution_data
+        else {
+            panic!("expected config update");
+        };
+        assert_eq!(
+            DaoConfig {
+                voting_period: 86400,
+                approval_threshold: 60,
+                ..config.config
+            },
+            new_config
+        );
+
+        // 相同版本可以重复迁移
+        let msg = MigrateMsg {
+            accepted_denoms: None,
+        };
+        let res = migrate(deps.as_mut(), mock_env(), msg).unwrap();
+        assert!(!res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "state_migration"));
+    }
+
+    #[test]
+    fn migrate_rejects_downgrade_and_foreign_contract() {
+        let mut deps = mock_dependencies();
+
+        set_contract_version(deps.as_mut().storage, CW2_CONTRACT_NAME, "99.0.0").unwrap();
+        let msg = MigrateMsg {
+            accepted_denoms: None,
+        };
+        let err = migrate(deps.as_mut(), mock_env(), msg.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::CannotDowngrade { .. }));
+
+        set_contract_version(deps.as_mut().storage, "crates.io:cw721-base", "0.1.0").unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), msg).unwrap_err();
+        assert!(matches!(err, ContractError::WrongContract { .. }));
+    }
+}
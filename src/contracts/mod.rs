@@ -1,3 +1,9 @@
 pub mod execute;
 pub mod instantiate;
+pub mod migrate;
 pub mod query;
+pub mod reply;
+
+/// cw2 记录的合约名称和版本，迁移时用于校验
+pub const CW2_CONTRACT_NAME: &str = concat!("crates.io:", env!("CARGO_PKG_NAME"));
+pub const CW2_CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
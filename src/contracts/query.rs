@@ -1,27 +1,116 @@
-use cosmwasm_std::{entry_point, to_json_binary, Addr, Binary, Deps, Env, StdError, StdResult};
+use cosmwasm_std::{
+    entry_point, to_json_binary, Addr, Binary, Coin, Deps, Env, StdError, StdResult,
+};
 
-use crate::helpers::is_dao_member;
+use crate::helpers::{
+    is_dao_member, is_review_expired, member_weight, normalize_doi, reputation_at, tally_votes,
+    MAX_LEADERBOARD_SIZE,
+};
 use crate::msg::{
-    AccessLevel, BaseCitationFeeResponse, Citation, ContractInfoResponse, DataItem, DataVersion,
-    NumTokensResponse, OwnerOfResponse, Proposal, ProposalStatus, QueryMsg, TokenInfoResponse,
-    VoteChoice, VoteCount,
+    AccessLevel, AllNftInfoResponse, Approval, ApprovalResponse, ApprovalsResponse,
+    BaseCitationFeeResponse, Citation, CitationCountsResponse, CitationEdgesResponse,
+    ContractInfoResponse, ContractOwnerResponse, DataItem, DataVersion, DelegatorsResponse,
+    DepositStatus, EffectivePowerResponse, Expiration, FeeConfigResponse, NftInfoResponse,
+    NumTokensResponse, OperatorResponse, OperatorsResponse, OutstandingDeposit,
+    OutstandingDepositsResponse, OwnerOfResponse, PaymentConfigResponse, Proposal, ProposalStatus,
+    QueryMsg, Reputation, ReputationLeaderboardResponse, ReputationResponse,
+    ReviewAssignmentsResponse, ReviewsResponse, TokenInfoResponse, TokensByOwnerResponse,
+    TokensResponse, TreasuryBalanceResponse, Veto, VetoesResponse, VoteNoShowsResponse,
 };
 use crate::state::{
-    ACCESS_CONTROLS, AUTHORIZED_USERS, BASE_CITATION_FEE, CITATIONS, CONTRACT_NAME, CONTRACT_OWNER,
-    CONTRACT_SYMBOL, DAO_CONFIG, DAO_MEMBERS, DATA_ITEMS, DATA_VERSIONS, PAPER_DOIS, PROPOSALS,
-    TOKEN_COUNT, TOKEN_OWNERS, VOTES, VOTE_COUNTS,
+    ACCESS_CONTROLS, AUTHORIZED_USERS, CITATIONS, CITATION_COUNTS, CITATION_EDGES, CONTRACT_NAME,
+    CONTRACT_OWNER, CONTRACT_SYMBOL, DAO_CONFIG, DAO_MEMBERS, DATA_ITEMS, DATA_VERSIONS,
+    DELEGATIONS, DOI_INDEX, FEE_CONFIG, OPERATOR_APPROVALS, PAPER_DOIS, PAYMENT_CONFIG,
+    PENDING_OWNER, PROPOSALS, PROPOSAL_POWER, PROPOSAL_VOTERS, REPUTATION_LEADERBOARD, REVIEWS,
+    REVIEW_ASSIGNMENTS, REVIEW_NO_SHOWS, TOKEN_APPROVALS, TOKEN_COUNT, TOKEN_OWNERS, TREASURY,
+    VETOES, VOTES, VOTE_COUNTS, VOTE_NO_SHOWS,
 };
 
 #[entry_point]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::OwnerOf { token_id } => to_json_binary(&query_owner_of(deps, token_id)?),
-        QueryMsg::TokenInfo { token_id } => to_json_binary(&query_token_info(deps, token_id)?),
+        // cw721 标准查询
+        QueryMsg::OwnerOf {
+            token_id,
+            include_expired,
+        } => to_json_binary(&query_owner_of(
+            deps,
+            &env,
+            token_id,
+            include_expired.unwrap_or(false),
+        )?),
+        QueryMsg::Approval {
+            token_id,
+            spender,
+            include_expired,
+        } => to_json_binary(&query_approval(
+            deps,
+            &env,
+            token_id,
+            spender,
+            include_expired.unwrap_or(false),
+        )?),
+        QueryMsg::Approvals {
+            token_id,
+            include_expired,
+        } => to_json_binary(&query_approvals(
+            deps,
+            &env,
+            token_id,
+            include_expired.unwrap_or(false),
+        )?),
+        QueryMsg::Operator {
+            owner,
+            operator,
+            include_expired,
+        } => to_json_binary(&query_operator(
+            deps,
+            &env,
+            owner,
+            operator,
+            include_expired.unwrap_or(false),
+        )?),
+        QueryMsg::AllOperators {
+            owner,
+            include_expired,
+            start_after,
+            limit,
+        } => to_json_binary(&query_all_operators(
+            deps,
+            &env,
+            owner,
+            include_expired.unwrap_or(false),
+            start_after,
+            limit,
+        )?),
+        QueryMsg::NumTokens {} => to_json_binary(&query_num_tokens(deps)?),
+        QueryMsg::ContractInfo {} => to_json_binary(&query_contract_info(deps)?),
+        QueryMsg::NftInfo { token_id } => to_json_binary(&query_nft_info(deps, token_id)?),
+        QueryMsg::AllNftInfo {
+            token_id,
+            include_expired,
+        } => to_json_binary(&query_all_nft_info(
+            deps,
+            &env,
+            token_id,
+            include_expired.unwrap_or(false),
+        )?),
+        QueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        } => to_json_binary(&query_tokens(deps, owner, start_after, limit)?),
         QueryMsg::AllTokens { start_after, limit } => {
             to_json_binary(&query_all_tokens(deps, start_after, limit)?)
         }
-        QueryMsg::NumTokens {} => to_json_binary(&query_num_tokens(deps)?),
-        QueryMsg::ContractInfo {} => to_json_binary(&query_contract_info(deps)?),
+
+        QueryMsg::TokenInfo { token_id } => to_json_binary(&query_token_info(deps, token_id)?),
+        QueryMsg::TokensByOwner {
+            owner,
+            start_after,
+            limit,
+        } => to_json_binary(&query_tokens_by_owner(deps, owner, start_after, limit)?),
+        QueryMsg::GetContractOwner {} => to_json_binary(&query_contract_owner(deps, env)?),
         QueryMsg::GetDataItem { token_id } => to_json_binary(&query_data_item(deps, token_id)?),
         QueryMsg::GetDataVersions { token_id } => {
             to_json_binary(&query_data_versions(deps, token_id)?)
@@ -34,8 +123,24 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
 
         QueryMsg::GetCitations { paper_id } => to_json_binary(&query_citations(deps, paper_id)?),
+        QueryMsg::GetReferences {
+            paper_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_references(deps, paper_id, start_after, limit)?),
+        QueryMsg::GetCitedBy {
+            paper_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_cited_by(deps, paper_id, start_after, limit)?),
+        QueryMsg::GetCitationCounts { paper_id } => {
+            to_json_binary(&query_citation_counts(deps, paper_id)?)
+        }
         QueryMsg::GetPaperDoi { paper_id } => to_json_binary(&query_paper_doi(deps, paper_id)?),
+        QueryMsg::GetPaperByDoi { doi } => to_json_binary(&query_paper_by_doi(deps, doi)?),
         QueryMsg::GetBaseCitationFee {} => to_json_binary(&query_base_citation_fee(deps)?),
+        QueryMsg::GetPaymentConfig {} => to_json_binary(&query_payment_config(deps)?),
+        QueryMsg::GetFeeConfig {} => to_json_binary(&query_fee_config(deps)?),
 
         // DAO queries
         QueryMsg::GetDaoMembers {} => to_json_binary(&query_dao_members(deps)?),
@@ -54,15 +159,191 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetVoteCount { proposal_id } => {
             to_json_binary(&query_vote_count(deps, proposal_id)?)
         }
+        QueryMsg::GetVoteNoShows { proposal_id } => {
+            to_json_binary(&query_vote_no_shows(deps, proposal_id)?)
+        }
+        QueryMsg::GetOutstandingDeposits { start_after, limit } => {
+            to_json_binary(&query_outstanding_deposits(deps, &env, start_after, limit)?)
+        }
         QueryMsg::GetMemberVotingPower { member } => {
             to_json_binary(&query_member_voting_power(deps, member)?)
         }
+        QueryMsg::GetDelegators {
+            member,
+            start_after,
+            limit,
+        } => to_json_binary(&query_delegators(deps, member, start_after, limit)?),
+        QueryMsg::GetReviews { proposal_id } => to_json_binary(&query_reviews(deps, proposal_id)?),
+        QueryMsg::GetReviewerAssignments {
+            reviewer,
+            start_after,
+            limit,
+        } => to_json_binary(&query_reviewer_assignments(
+            deps,
+            env,
+            reviewer,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::TreasuryBalance {} => to_json_binary(&query_treasury_balance(deps)?),
+        QueryMsg::GetReputation { address } => {
+            to_json_binary(&query_reputation(deps, env, address)?)
+        }
+        QueryMsg::GetReputationLeaderboard { limit } => {
+            to_json_binary(&query_reputation_leaderboard(deps, env, limit)?)
+        }
+        QueryMsg::GetVetoes { proposal_id } => to_json_binary(&query_vetoes(deps, proposal_id)?),
+        QueryMsg::GetEffectivePower {
+            proposal_id,
+            member,
+        } => to_json_binary(&query_effective_power(deps, proposal_id, member)?),
     }
 }
 
-pub fn query_owner_of(deps: Deps, token_id: String) -> StdResult<OwnerOfResponse> {
+pub fn query_owner_of(
+    deps: Deps,
+    env: &Env,
+    token_id: String,
+    include_expired: bool,
+) -> StdResult<OwnerOfResponse> {
+    let owner = TOKEN_OWNERS.load(deps.storage, &token_id)?;
+    let approvals = token_approvals(deps, env, &token_id, include_expired)?;
+    Ok(OwnerOfResponse {
+        owner: owner.to_string(),
+        approvals,
+    })
+}
+
+pub fn query_approval(
+    deps: Deps,
+    env: &Env,
+    token_id: String,
+    spender: String,
+    include_expired: bool,
+) -> StdResult<ApprovalResponse> {
     let owner = TOKEN_OWNERS.load(deps.storage, &token_id)?;
-    Ok(OwnerOfResponse { owner })
+
+    // 所有者视为永久批准
+    if owner == spender {
+        return Ok(ApprovalResponse {
+            approval: Approval {
+                spender,
+                expires: Expiration::Never {},
+            },
+        });
+    }
+
+    match TOKEN_APPROVALS.may_load(deps.storage, (&token_id, &spender))? {
+        Some(expires) if include_expired || !expires.is_expired(&env.block) => {
+            Ok(ApprovalResponse {
+                approval: Approval { spender, expires },
+            })
+        }
+        _ => Err(StdError::not_found("Approval not found")),
+    }
+}
+
+pub fn query_approvals(
+    deps: Deps,
+    env: &Env,
+    token_id: String,
+    include_expired: bool,
+) -> StdResult<ApprovalsResponse> {
+    TOKEN_OWNERS.load(deps.storage, &token_id)?;
+    let approvals = token_approvals(deps, env, &token_id, include_expired)?;
+    Ok(ApprovalsResponse { approvals })
+}
+
+pub fn query_operator(
+    deps: Deps,
+    env: &Env,
+    owner: String,
+    operator: String,
+    include_expired: bool,
+) -> StdResult<OperatorResponse> {
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    let operator_addr = deps.api.addr_validate(&operator)?;
+
+    match OPERATOR_APPROVALS
+        .may_load(deps.storage, (owner_addr.as_str(), operator_addr.as_str()))?
+    {
+        Some(expires) if include_expired || !expires.is_expired(&env.block) => {
+            Ok(OperatorResponse {
+                approval: Approval {
+                    spender: operator,
+                    expires,
+                },
+            })
+        }
+        _ => Err(StdError::not_found("Approval not found")),
+    }
+}
+
+pub fn query_all_operators(
+    deps: Deps,
+    env: &Env,
+    owner: String,
+    include_expired: bool,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<OperatorsResponse> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    let start = start_after
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let operators: StdResult<Vec<Approval>> = OPERATOR_APPROVALS
+        .prefix(owner_addr.as_str())
+        .range(deps.storage, start, None, cosmwasm_std::Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, expires)) => include_expired || !expires.is_expired(&env.block),
+            Err(_) => true,
+        })
+        .take(limit)
+        .map(|item| item.map(|(spender, expires)| Approval { spender, expires }))
+        .collect();
+
+    Ok(OperatorsResponse {
+        operators: operators?,
+    })
+}
+
+pub fn query_nft_info(deps: Deps, token_id: String) -> StdResult<NftInfoResponse<DataItem>> {
+    let data_item = DATA_ITEMS.load(deps.storage, &token_id)?;
+    Ok(NftInfoResponse {
+        token_uri: Some(data_item.metadata_uri.clone()),
+        extension: data_item,
+    })
+}
+
+pub fn query_all_nft_info(
+    deps: Deps,
+    env: &Env,
+    token_id: String,
+    include_expired: bool,
+) -> StdResult<AllNftInfoResponse<DataItem>> {
+    let access = query_owner_of(deps, env, token_id.clone(), include_expired)?;
+    let info = query_nft_info(deps, token_id)?;
+    Ok(AllNftInfoResponse { access, info })
+}
+
+/// 列出 token 的单独批准
+fn token_approvals(
+    deps: Deps,
+    env: &Env,
+    token_id: &str,
+    include_expired: bool,
+) -> StdResult<Vec<Approval>> {
+    TOKEN_APPROVALS
+        .prefix(token_id)
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, expires)) => include_expired || !expires.is_expired(&env.block),
+            Err(_) => true,
+        })
+        .map(|item| item.map(|(spender, expires)| Approval { spender, expires }))
+        .collect()
 }
 
 pub fn query_token_info(deps: Deps, token_id: String) -> StdResult<TokenInfoResponse> {
@@ -82,11 +363,122 @@ pub fn query_citations(deps: Deps, paper_id: String) -> StdResult<Vec<Citation>>
         .map(|citations| citations.unwrap_or_default())
 }
 
-pub fn query_all_tokens(
+/// 按被引论文 ID 分页列出论文引用的论文
+pub fn query_references(
+    deps: Deps,
+    paper_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<CitationEdgesResponse> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let start = start_after
+        .as_deref()
+        .map(cw_storage_plus::Bound::exclusive);
+
+    let edges = CITATION_EDGES
+        .prefix(&paper_id)
+        .range(deps.storage, start, None, cosmwasm_std::Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, edge)| edge))
+        .collect::<StdResult<_>>()?;
+    Ok(CitationEdgesResponse { edges })
+}
+
+/// 按引用方论文 ID 分页列出引用该论文的论文
+pub fn query_cited_by(
+    deps: Deps,
+    paper_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<CitationEdgesResponse> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let start =
+        start_after.map(|citing| cw_storage_plus::Bound::exclusive((citing, paper_id.clone())));
+
+    let edges = CITATION_EDGES
+        .idx
+        .cited
+        .prefix(paper_id.clone())
+        .range(deps.storage, start, None, cosmwasm_std::Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, edge)| edge))
+        .collect::<StdResult<_>>()?;
+    Ok(CitationEdgesResponse { edges })
+}
+
+/// 查询论文的引用计数
+pub fn query_citation_counts(deps: Deps, paper_id: String) -> StdResult<CitationCountsResponse> {
+    let counts = CITATION_COUNTS
+        .may_load(deps.storage, &paper_id)?
+        .unwrap_or_default();
+    let paid_citations = CITATIONS
+        .may_load(deps.storage, &paper_id)?
+        .map_or(0, |citations| citations.len() as u64);
+    Ok(CitationCountsResponse {
+        paper_id,
+        counts,
+        paid_citations,
+    })
+}
+
+pub fn query_tokens(
+    deps: Deps,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    let tokens = owned_token_ids(deps, owner, start_after, limit)?;
+    Ok(TokensResponse { tokens })
+}
+
+/// 按所有者索引分页列出 token 及其数据项
+pub fn query_tokens_by_owner(
+    deps: Deps,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensByOwnerResponse> {
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    let tokens = owned_token_ids(deps, owner, start_after, limit)?
+        .into_iter()
+        .map(|token_id| {
+            let data_item = DATA_ITEMS.load(deps.storage, &token_id)?;
+            Ok(TokenInfoResponse {
+                token_id,
+                owner: owner_addr.clone(),
+                data_item,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    Ok(TokensByOwnerResponse { tokens })
+}
+
+/// 通过所有者索引读取 token ID，无需扫描全部 token
+fn owned_token_ids(
     deps: Deps,
+    owner: String,
     start_after: Option<String>,
     limit: Option<u32>,
 ) -> StdResult<Vec<String>> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    let start = start_after.map(cw_storage_plus::Bound::exclusive);
+
+    TOKEN_OWNERS
+        .idx
+        .owner
+        .prefix(owner_addr)
+        .keys(deps.storage, start, None, cosmwasm_std::Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+pub fn query_all_tokens(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
     let limit = limit.unwrap_or(30).min(100) as usize;
     let start = start_after
         .as_deref()
@@ -98,7 +490,7 @@ pub fn query_all_tokens(
         .map(|item| item.map(|(k, _)| k))
         .collect();
 
-    tokens
+    Ok(TokensResponse { tokens: tokens? })
 }
 
 pub fn query_num_tokens(deps: Deps) -> StdResult<NumTokensResponse> {
@@ -109,12 +501,16 @@ pub fn query_num_tokens(deps: Deps) -> StdResult<NumTokensResponse> {
 pub fn query_contract_info(deps: Deps) -> StdResult<ContractInfoResponse> {
     let name = CONTRACT_NAME.load(deps.storage)?;
     let symbol = CONTRACT_SYMBOL.load(deps.storage)?;
-    let owner = CONTRACT_OWNER.load(deps.storage)?;
 
-    Ok(ContractInfoResponse {
-        name,
-        symbol,
-        owner,
+    Ok(ContractInfoResponse { name, symbol })
+}
+
+pub fn query_contract_owner(deps: Deps, env: Env) -> StdResult<ContractOwnerResponse> {
+    Ok(ContractOwnerResponse {
+        owner: CONTRACT_OWNER
+            .may_load(deps.storage)?
+            .unwrap_or(env.contract.address),
+        pending_owner: PENDING_OWNER.may_load(deps.storage)?,
     })
 }
 
@@ -128,8 +524,9 @@ pub fn query_data_versions(deps: Deps, token_id: String) -> StdResult<Vec<DataVe
 
 pub fn query_authorized_users(deps: Deps, token_id: String) -> StdResult<Vec<Addr>> {
     AUTHORIZED_USERS
-        .may_load(deps.storage, &token_id)
-        .map(|users| users.unwrap_or_default())
+        .prefix(&token_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect()
 }
 
 pub fn query_access_level(deps: Deps, token_id: String, user: String) -> StdResult<AccessLevel> {
@@ -142,14 +539,33 @@ pub fn query_paper_doi(deps: Deps, paper_id: String) -> StdResult<String> {
     PAPER_DOIS.load(deps.storage, &paper_id)
 }
 
+/// 通过 DOI 查找论文 token，DOI 会先规范化
+pub fn query_paper_by_doi(deps: Deps, doi: String) -> StdResult<TokenInfoResponse> {
+    let doi = normalize_doi(&doi).map_err(|e| StdError::generic_err(e.to_string()))?;
+    let token_id = DOI_INDEX.load(deps.storage, &doi)?;
+    query_token_info(deps, token_id)
+}
+
 pub fn query_base_citation_fee(deps: Deps) -> StdResult<BaseCitationFeeResponse> {
-    let fee = BASE_CITATION_FEE.load(deps.storage)?;
-    Ok(BaseCitationFeeResponse { fee })
+    let config = FEE_CONFIG.load(deps.storage)?;
+    Ok(BaseCitationFeeResponse {
+        fee: config.base_citation_fee.amount,
+        denom: config.base_citation_fee.denom,
+    })
+}
+
+pub fn query_fee_config(deps: Deps) -> StdResult<FeeConfigResponse> {
+    let config = FEE_CONFIG.load(deps.storage)?;
+    Ok(FeeConfigResponse { config })
+}
+
+pub fn query_payment_config(deps: Deps) -> StdResult<PaymentConfigResponse> {
+    let config = PAYMENT_CONFIG.load(deps.storage)?;
+    Ok(PaymentConfigResponse { config })
 }
 
 // DAO 查询函数实现
 
-/// 查询所有 DAO 成员
 // pub fn query_dao_members(deps: Deps) -> StdResult<crate::msg::DaoMembersResponse> {
 //     let members: StdResult<Vec<Addr>> = DAO_MEMBERS
 //         .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
@@ -176,19 +592,11 @@ pub fn query_base_citation_fee(deps: Deps) -> StdResult<BaseCitationFeeResponse>
 //         total_count,
 //     })
 //
+/// 查询所有 DAO 成员
 pub fn query_dao_members(deps: Deps) -> StdResult<crate::msg::DaoMembersResponse> {
     let members: Result<Vec<Addr>, StdError> = DAO_MEMBERS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .filter_map(|item| match item {
-            Ok((addr_str, is_member)) => {
-                if is_member {
-                    Some(deps.api.addr_validate(&addr_str))
-                } else {
-                    None
-                }
-            }
-            Err(e) => Some(Err(e)),
-        })
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|item| item.and_then(|addr_str| deps.api.addr_validate(&addr_str)))
         .collect();
 
     let members = members?;
@@ -291,38 +699,224 @@ pub fn query_vote_count(deps: Deps, proposal_id: u64) -> StdResult<crate::msg::V
     let vote_count = if let Some(count) = vote_count {
         count
     } else {
-        // 如果缓存中没有，实时计算投票统计
-        let mut yes = 0u64;
-        let mut no = 0u64;
-        let mut abstain = 0u64;
-
-        // 遍历所有投票记录
-        let votes: StdResult<Vec<_>> = VOTES
-            .prefix(proposal_id)
-            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-            .collect();
-
-        for vote_result in votes? {
-            let (_, vote) = vote_result;
-            match vote.choice {
-                VoteChoice::Yes => yes += 1,
-                VoteChoice::No => no += 1,
-                VoteChoice::Abstain => abstain += 1,
-            }
-        }
+        // 如果缓存中没有，按提案创建时的投票者和委托快照实时计算
+        tally_votes(deps.storage, proposal_id)?
+    };
+
+    Ok(crate::msg::VoteCountResponse { vote_count })
+}
+
+/// 查询提交-揭示投票中未揭示承诺的成员
+pub fn query_vote_no_shows(deps: Deps, proposal_id: u64) -> StdResult<VoteNoShowsResponse> {
+    PROPOSALS.load(deps.storage, proposal_id)?;
+    let no_shows = VOTE_NO_SHOWS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    Ok(VoteNoShowsResponse { no_shows })
+}
+
+/// 列出仍由合约托管的提案押金
+pub fn query_outstanding_deposits(
+    deps: Deps,
+    env: &Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<OutstandingDepositsResponse> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let start = start_after.map(cw_storage_plus::Bound::exclusive);
+
+    let deposits = PROPOSALS
+        .range(deps.storage, start, None, cosmwasm_std::Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((id, proposal)) => match proposal.deposit {
+                Some(ref deposit) if deposit.status == DepositStatus::Held => {
+                    // 投票期结束或状态已确定的提案可以尝试取回押金，评审中的提案在评审超时后过期
+                    let voting_ended = match proposal.status {
+                        ProposalStatus::UnderReview => is_review_expired(env, &proposal),
+                        ProposalStatus::ReviewReveal => false,
+                        // 提交-揭示投票要等揭示期结束
+                        ProposalStatus::Active | ProposalStatus::VoteReveal => {
+                            env.block.time.seconds()
+                                > proposal.voting_end + proposal.vote_reveal_period
+                        }
+                        _ => true,
+                    };
+                    Some(Ok(OutstandingDeposit {
+                        proposal_id: id,
+                        depositor: proposal.proposer,
+                        amount: deposit.amount.clone(),
+                        voting_ended,
+                    }))
+                }
+                _ => None,
+            },
+            Err(e) => Some(Err(e)),
+        })
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+
+    Ok(OutstandingDepositsResponse { deposits })
+}
 
-        // 获取 DAO 成员总数作为有资格投票的总数
-        let total_eligible = query_dao_members(deps)?.total_count;
+/// 查询文章提案收到的评审
+pub fn query_reviews(deps: Deps, proposal_id: u64) -> StdResult<ReviewsResponse> {
+    let reviews = REVIEWS
+        .prefix(proposal_id)
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|item| item.map(|(_, review)| review))
+        .collect::<StdResult<_>>()?;
+    let no_shows = REVIEW_NO_SHOWS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    Ok(ReviewsResponse { reviews, no_shows })
+}
 
-        VoteCount {
-            yes,
-            no,
-            abstain,
-            total_eligible,
+/// 查询评审人待完成的评审任务
+/// 已过截止时间或提案已离开评审阶段的任务不再列出
+pub fn query_reviewer_assignments(
+    deps: Deps,
+    env: Env,
+    reviewer: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ReviewAssignmentsResponse> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let reviewer_addr = deps.api.addr_validate(&reviewer)?;
+    let start = start_after.map(cw_storage_plus::Bound::exclusive);
+    let now = env.block.time.seconds();
+
+    let mut assignments = vec![];
+    for item in REVIEW_ASSIGNMENTS.prefix(&reviewer_addr).range(
+        deps.storage,
+        start,
+        None,
+        cosmwasm_std::Order::Ascending,
+    ) {
+        let (proposal_id, assignment) = item?;
+        if assignment.deadline < now {
+            continue;
         }
-    };
+        let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+        if proposal.status != ProposalStatus::UnderReview {
+            continue;
+        }
+        assignments.push(assignment);
+        if assignments.len() >= limit {
+            break;
+        }
+    }
 
-    Ok(crate::msg::VoteCountResponse { vote_count })
+    Ok(ReviewAssignmentsResponse { assignments })
+}
+
+/// 查询 DAO 金库余额
+pub fn query_treasury_balance(deps: Deps) -> StdResult<TreasuryBalanceResponse> {
+    let balances = TREASURY
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<_>>()?;
+    Ok(TreasuryBalanceResponse { balances })
+}
+
+/// 查询研究者的声誉，分数衰减到当前区块时间
+pub fn query_reputation(deps: Deps, env: Env, address: String) -> StdResult<ReputationResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let config = DAO_CONFIG.load(deps.storage)?.reputation_config;
+    let reputation = reputation_at(deps.storage, &address, &config, env.block.time.seconds())?;
+    Ok(ReputationResponse { reputation })
+}
+
+/// 按衰减后的分数从高到低列出声誉排行，分数相同时按地址排序
+/// 只在数量有上限的候选列表中排序，候选由写入声誉时维护
+pub fn query_reputation_leaderboard(
+    deps: Deps,
+    env: Env,
+    limit: Option<u32>,
+) -> StdResult<ReputationLeaderboardResponse> {
+    let limit = limit.unwrap_or(10).min(MAX_LEADERBOARD_SIZE as u32) as usize;
+    let config = DAO_CONFIG.load(deps.storage)?.reputation_config;
+    let now = env.block.time.seconds();
+
+    // 只读取数量有上限的排行榜候选，不遍历全部声誉记录
+    let mut entries: Vec<Reputation> = REPUTATION_LEADERBOARD
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .iter()
+        .map(|address| reputation_at(deps.storage, address, &config, now))
+        .collect::<StdResult<_>>()?;
+    entries.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.address.cmp(&b.address))
+    });
+    entries.truncate(limit);
+    Ok(ReputationLeaderboardResponse { entries })
+}
+
+/// 查询提案收到的否决
+pub fn query_vetoes(deps: Deps, proposal_id: u64) -> StdResult<VetoesResponse> {
+    PROPOSALS.load(deps.storage, proposal_id)?;
+    let vetoes = VETOES
+        .prefix(proposal_id)
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|item| item.map(|(member, reason)| Veto { member, reason }))
+        .collect::<StdResult<_>>()?;
+    Ok(VetoesResponse { vetoes })
+}
+
+/// 查询委托给成员的委托人
+pub fn query_delegators(
+    deps: Deps,
+    member: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<DelegatorsResponse> {
+    let limit = limit.unwrap_or(30).min(100) as usize;
+    let member_addr = deps.api.addr_validate(&member)?;
+    let start = start_after.map(cw_storage_plus::Bound::exclusive);
+
+    let delegate = DELEGATIONS.may_load(deps.storage, member_addr.as_str())?;
+    let delegators = DELEGATIONS
+        .idx
+        .delegate
+        .prefix(member_addr)
+        .keys(deps.storage, start, None, cosmwasm_std::Order::Ascending)
+        .take(limit)
+        .map(|key| key.map(Addr::unchecked))
+        .collect::<StdResult<_>>()?;
+
+    Ok(DelegatorsResponse {
+        delegate,
+        delegators,
+    })
+}
+
+/// 按提案快照计算成员可行使的投票权
+/// 委托人的权重沿委托链传递，直到遇到已直接投票的成员为止
+pub fn query_effective_power(
+    deps: Deps,
+    proposal_id: u64,
+    member: String,
+) -> StdResult<EffectivePowerResponse> {
+    PROPOSALS.load(deps.storage, proposal_id)?;
+    let member_addr = deps.api.addr_validate(&member)?;
+
+    let own_power = PROPOSAL_VOTERS
+        .may_load(deps.storage, (proposal_id, member_addr.as_str()))?
+        .unwrap_or(0);
+    // 委托在快照时已解析，成员持有的投票权随投票增量更新
+    let total_power = PROPOSAL_POWER
+        .may_load(deps.storage, (proposal_id, member_addr.as_str()))?
+        .unwrap_or(own_power);
+    let delegated_power = total_power.saturating_sub(own_power);
+
+    Ok(EffectivePowerResponse {
+        own_power,
+        delegated_power,
+        total_power,
+    })
 }
 
 /// 查询成员投票权力
@@ -333,8 +927,8 @@ pub fn query_member_voting_power(
     let member_addr = deps.api.addr_validate(&member)?;
     let is_member = is_dao_member(deps, &member_addr)?;
 
-    // 在当前实现中，每个 DAO 成员都有相等的投票权重（1 票）
-    let power = if is_member { 1u64 } else { 0u64 };
+    // 投票权力等于成员权重，非成员为 0
+    let power = member_weight(deps, &member_addr)?;
 
     Ok(crate::msg::VotingPowerResponse { power, is_member })
 }
@@ -0,0 +1,34 @@
+use crate::error::ContractError;
+use crate::helpers::validate_proposal_status_transition;
+use crate::msg::ProposalStatus;
+use crate::state::{PENDING_DISPATCH, PROPOSALS};
+use cosmwasm_std::{entry_point, DepsMut, Env, Reply, Response, SubMsgResult};
+
+/// 处理自定义提案消息批次的执行结果
+/// reply ID 为提案 ID，只在批次失败时回调，此时批次内的消息已全部回滚
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let proposal_id = msg.id;
+    let mut proposal = PROPOSALS
+        .load(deps.storage, proposal_id)
+        .map_err(|_| ContractError::ProposalNotFound {})?;
+
+    let response = Response::new()
+        .add_attribute("method", "reply")
+        .add_attribute("proposal_id", proposal_id.to_string());
+
+    match msg.result {
+        SubMsgResult::Ok(_) => Ok(response.add_attribute("execution_status", "success")),
+        SubMsgResult::Err(err) => {
+            // 批次回滚后执行时保存的标记仍在，需要清除
+            PENDING_DISPATCH.remove(deps.storage);
+            validate_proposal_status_transition(&proposal.status, &ProposalStatus::Failed)?;
+            proposal.status = ProposalStatus::Failed;
+            PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+            Ok(response
+                .add_attribute("execution_status", "failed")
+                .add_attribute("error", err))
+        }
+    }
+}
@@ -15,18 +15,60 @@ pub enum ContractError {
     #[error("Data is frozen")]
     DataFrozen {},
 
+    #[error("Data is retired")]
+    DataRetired {},
+
+    #[error("Token is frozen or has citations; burning requires DAO approval")]
+    BurnRequiresDaoApproval {},
+
     #[error("Insufficient payment")]
     InsufficientPayment {},
 
+    #[error("Payment exceeds the required {expected}")]
+    ExcessPayment { expected: String },
+
     #[error("Payment failed")]
     PaymentFailed {},
 
+    #[error("Denomination {denom} is not accepted")]
+    UnsupportedDenom { denom: String },
+
+    #[error("Unexpected funds sent: {denom}")]
+    UnexpectedFunds { denom: String },
+
+    #[error("Proposal already has votes")]
+    ProposalHasVotes {},
+
+    #[error("Invalid accepted denominations")]
+    InvalidDenoms {},
+
+    #[error("Denomination {denom} is still in use")]
+    DenomInUse { denom: String },
+
+    #[error("Fee share must not exceed 10000 basis points")]
+    InvalidFeeConfig {},
+
     #[error("Token already exists")]
     TokenExists {},
 
+    #[error("Invalid DOI: {doi}")]
+    InvalidDoi { doi: String },
+
+    #[error("DOI {doi} already exists")]
+    DuplicateDoi { doi: String },
+
     #[error("Not authorized")]
     NotAuthorized {},
 
+    #[error("Contract ownership has been renounced")]
+    OwnershipRenounced {},
+
+    #[error("No pending ownership transfer")]
+    NoPendingOwner {},
+
+    #[error("Cannot set approval that is already expired")]
+    Expired {},
+
     // DAO 相关错误
     #[error("Not a DAO member")]
     NotDaoMember {},
@@ -58,6 +100,136 @@ pub enum ContractError {
     #[error("Invalid voting threshold")]
     InvalidVotingThreshold {},
 
+    #[error("Invalid quorum")]
+    InvalidQuorum {},
+
+    #[error("Member weight must be greater than zero")]
+    InvalidMemberWeight {},
+
+    #[error("Cannot delegate voting power to yourself")]
+    CannotDelegateToSelf {},
+
+    #[error("Delegation would create a cycle")]
+    DelegationCycle {},
+
+    #[error("No active delegation")]
+    NoDelegation {},
+
+    #[error("Proposal is timelocked until {executable_at}")]
+    ProposalTimelocked { executable_at: u64 },
+
+    #[error("Not a veto council member")]
+    NotVetoCouncilMember {},
+
+    #[error("Proposal can only be vetoed during voting or the execution timelock")]
+    CannotVetoProposal {},
+
+    #[error("Already vetoed this proposal")]
+    AlreadyVetoed {},
+
+    #[error("Invalid veto council")]
+    InvalidVetoCouncil {},
+
+    #[error("Amendment must keep the proposal type and this type cannot be amended")]
+    InvalidAmendment {},
+
+    #[error("Invalid review config")]
+    InvalidReviewConfig {},
+
+    #[error("Only editors can assign reviewers")]
+    NotEditor {},
+
+    #[error("Proposal is not under review")]
+    ProposalNotUnderReview {},
+
+    #[error("Reviewer already assigned to this proposal")]
+    ReviewerAlreadyAssigned {},
+
+    #[error("Not an assigned reviewer for this proposal")]
+    NotAssignedReviewer {},
+
+    #[error("Review deadline has passed")]
+    ReviewDeadlinePassed {},
+
+    #[error("Review period has not ended")]
+    ReviewPeriodActive {},
+
+    #[error("Reviewed article proposals cannot be amended")]
+    ReviewedProposalLocked {},
+
+    #[error("Review score must be between 1 and 10")]
+    InvalidReviewScore {},
+
+    #[error("A paper cannot cite itself")]
+    SelfCitation {},
+
+    #[error("Paper {citing} already cites {cited}")]
+    DuplicateCitation { citing: String, cited: String },
+
+    #[error("Invalid reputation config")]
+    InvalidReputationConfig {},
+
+    #[error("Review not found")]
+    ReviewNotFound {},
+
+    #[error("Review already accepted")]
+    ReviewAlreadyAccepted {},
+
+    #[error("Review bounty requires peer review to be enabled")]
+    ReviewBountyUnavailable {},
+
+    #[error("Proposal has no review bounty")]
+    NoReviewBounty {},
+
+    #[error("Review bounty already settled")]
+    ReviewBountySettled {},
+
+    #[error("Review bounty cannot be settled while reviews are still due")]
+    ReviewBountyLocked {},
+
+    #[error("Commitment must be a hex-encoded sha256 hash")]
+    InvalidCommitment {},
+
+    #[error("Revealed values do not match the commitment")]
+    CommitmentMismatch {},
+
+    #[error("This phase requires a commitment instead of a plain submission")]
+    CommitmentRequired {},
+
+    #[error("Commit-reveal is not enabled for this proposal")]
+    CommitRevealDisabled {},
+
+    #[error("No commitment to reveal")]
+    NoCommitment {},
+
+    #[error("Reveal period has not ended")]
+    RevealPeriodActive {},
+
+    #[error("Reveal period has ended")]
+    RevealPeriodEnded {},
+
+    #[error("Insufficient treasury funds in {denom}")]
+    InsufficientTreasuryFunds { denom: String },
+
+    #[error("Proposal has no deposit to claim")]
+    NoDeposit {},
+
+    #[error("Deposit was slashed")]
+    DepositSlashed {},
+
+    #[error("Deposit already refunded")]
+    DepositAlreadyRefunded {},
+
+    #[error("Custom proposal messages cannot move contract funds; use a treasury spend proposal")]
+    CustomMsgMovesFunds {},
+
+    // 迁移相关错误
+    #[error("Cannot migrate from contract {contract}")]
+    WrongContract { contract: String },
+
+    #[error("Cannot migrate from version {from} to older version {to}")]
+    CannotDowngrade { from: String, to: String },
+
     #[error("Feature not implemented yet")]
     NotImplemented {},
 }
@@ -1,14 +1,68 @@
 use crate::error::ContractError;
-use crate::msg::{Proposal, ProposalStatus};
-use crate::state::{DAO_MEMBERS, PROPOSALS};
-use cosmwasm_std::{Addr, Deps, Env, StdResult};
+use crate::msg::{
+    DataItem, FeeConfig, Proposal, ProposalStatus, Reputation, ReputationConfig, ReviewConfig,
+    ReviewRecommendation, VetoCouncil, Vote, VoteChoice, VoteCount,
+};
+use crate::state::{
+    CONTRACT_OWNER, DAO_CONFIG, DAO_MEMBERS, FEE_CONFIG, LISTED_DENOMS, PAYMENT_CONFIG, PROPOSALS,
+    PROPOSAL_DELEGATIONS, PROPOSAL_POWER, PROPOSAL_VOTERS, REPUTATION, REPUTATION_LEADERBOARD,
+    VOTES, VOTE_COUNTS,
+};
+use cosmwasm_std::{Addr, Coin, Deps, Env, MessageInfo, Order, StdResult, Storage, Uint128};
+use sha2::{Digest, Sha256};
+
+/// 默认法定人数（百分比）
+pub const DEFAULT_QUORUM: u64 = 33;
+
+/// 默认声誉计分规则，分数半衰期为一年
+pub fn default_reputation_config() -> ReputationConfig {
+    ReputationConfig {
+        paper_points: 10,
+        citation_points: 2,
+        review_points: 5,
+        proposal_points: 3,
+        half_life: 31536000,
+        points_per_vote: 100,
+    }
+}
+
+/// 排行榜候选地址的数量上限，与排行榜查询的最大条数一致
+pub const MAX_LEADERBOARD_SIZE: usize = 100;
+
+/// 最长执行时间锁为 30 天（秒）
+pub const MAX_EXECUTION_DELAY: u64 = 2592000;
+
+/// 最长评审期限为 30 天（秒）
+pub const MAX_REVIEW_PERIOD: u64 = 2592000;
+
+/// 最长揭示期为 30 天（秒）
+pub const MAX_REVEAL_PERIOD: u64 = 2592000;
+
+/// 评审分数上限
+pub const MAX_REVIEW_SCORE: u8 = 10;
+
+/// 费用比例的基点分母
+pub const BPS_DENOMINATOR: u64 = 10_000;
 
 /// 检查地址是否为 DAO 成员
 /// 这是一个辅助函数，用于在所有需要 DAO 成员身份验证的地方进行检查
 pub fn is_dao_member(deps: Deps, address: &Addr) -> StdResult<bool> {
+    Ok(DAO_MEMBERS.has(deps.storage, address.as_str()))
+}
+
+/// 查询成员的投票权重，非成员为 0
+pub fn member_weight(deps: Deps, address: &Addr) -> StdResult<u64> {
     DAO_MEMBERS
         .may_load(deps.storage, address.as_str())
-        .map(|member| member.unwrap_or(false))
+        .map(|weight| weight.unwrap_or(0))
+}
+
+/// 验证成员投票权重必须大于 0
+pub fn validate_member_weight(weight: u64) -> Result<(), ContractError> {
+    if weight == 0 {
+        return Err(ContractError::InvalidMemberWeight {});
+    }
+    Ok(())
 }
 
 /// 验证调用者是否为 DAO 成员，如果不是则返回错误
@@ -20,6 +74,15 @@ pub fn ensure_dao_member(deps: Deps, address: &Addr) -> Result<(), ContractError
     Ok(())
 }
 
+/// 验证调用者是合约所有者，所有权已放弃时返回错误
+pub fn ensure_contract_owner(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    match CONTRACT_OWNER.may_load(deps.storage)? {
+        None => Err(ContractError::OwnershipRenounced {}),
+        Some(owner) if owner != *sender => Err(ContractError::NotAuthorized {}),
+        Some(_) => Ok(()),
+    }
+}
+
 /// 检查提案是否存在
 pub fn ensure_proposal_exists(deps: Deps, proposal_id: u64) -> Result<Proposal, ContractError> {
     PROPOSALS
@@ -83,10 +146,467 @@ pub fn ensure_can_execute_proposal(env: &Env, proposal: &Proposal) -> Result<(),
         return Err(ContractError::ProposalAlreadyExecuted {});
     }
 
-    if is_proposal_expired(env, proposal) {
+    // 提案可能在投票期结束时才通过，因此执行窗口从时间锁结束时起算
+    if let Some(executable_at) = proposal.executable_at {
+        if env.block.time.seconds() < executable_at {
+            return Err(ContractError::ProposalTimelocked { executable_at });
+        }
+    }
+
+    // 超过执行截止时间的提案过期，没有截止时间的旧提案沿用投票期限
+    let deadline = proposal.execution_deadline.unwrap_or(proposal.voting_end);
+    if env.block.time.seconds() > deadline {
         return Err(ContractError::ProposalExpired {});
     }
+    Ok(())
+}
+
+/// 验证否决委员会：名称不能为空，成员不能重复，门槛在 1 到成员数之间
+pub fn validate_veto_council(council: &VetoCouncil) -> Result<(), ContractError> {
+    if council.name.trim().is_empty()
+        || council.threshold == 0
+        || council.threshold > council.members.len() as u64
+    {
+        return Err(ContractError::InvalidVetoCouncil {});
+    }
+    for (i, member) in council.members.iter().enumerate() {
+        if council.members[..i].contains(member) {
+            return Err(ContractError::InvalidVetoCouncil {});
+        }
+    }
+    Ok(())
+}
+
+/// 验证同行评审设置：编辑不能为空或重复，至少需要一份评审，评审期限在 30 天以内
+pub fn validate_review_config(config: &ReviewConfig) -> Result<(), ContractError> {
+    if config.editors.is_empty()
+        || config.required_reviews == 0
+        || config.review_period == 0
+        || config.review_period > MAX_REVIEW_PERIOD
+        || config.reveal_period > MAX_REVEAL_PERIOD
+    {
+        return Err(ContractError::InvalidReviewConfig {});
+    }
+    for (i, editor) in config.editors.iter().enumerate() {
+        if config.editors[..i].contains(editor) {
+            return Err(ContractError::InvalidReviewConfig {});
+        }
+    }
+    Ok(())
+}
+
+/// 验证声誉计分规则：折算票数的分数不能为 0
+pub fn validate_reputation_config(config: &ReputationConfig) -> Result<(), ContractError> {
+    if config.points_per_vote == 0 {
+        return Err(ContractError::InvalidReputationConfig {});
+    }
+    Ok(())
+}
+
+/// 计算经过 `elapsed` 秒衰减后的分数
+/// 每个完整的半衰期减半，半衰期内按线性插值衰减
+pub fn decayed_score(score: u64, elapsed: u64, half_life: u64) -> u64 {
+    if half_life == 0 {
+        return score;
+    }
+    let halvings = elapsed / half_life;
+    if halvings >= 64 {
+        return 0;
+    }
+    let score = score >> halvings;
+    let remainder = elapsed % half_life;
+    score - (score as u128 * remainder as u128 / (2 * half_life as u128)) as u64
+}
+
+/// 研究者在指定时间的声誉，没有记录时返回空记录
+pub fn reputation_at(
+    storage: &dyn Storage,
+    address: &Addr,
+    config: &ReputationConfig,
+    now: u64,
+) -> StdResult<Reputation> {
+    let mut reputation = REPUTATION
+        .may_load(storage, address)?
+        .unwrap_or(Reputation {
+            address: address.clone(),
+            papers_published: 0,
+            citations_received: 0,
+            reviews_completed: 0,
+            proposals_passed: 0,
+            score: 0,
+            updated_at: now,
+        });
+    reputation.score = decayed_score(
+        reputation.score,
+        now.saturating_sub(reputation.updated_at),
+        config.half_life,
+    );
+    reputation.updated_at = now;
+    Ok(reputation)
+}
+
+/// 计入声誉的事件
+pub enum ReputationEvent {
+    PaperPublished,
+    CitationReceived,
+    ReviewCompleted,
+    ProposalPassed,
+}
+
+/// 记录声誉事件：先把已有分数衰减到当前时间，再加上事件对应的分数
+pub fn record_reputation(
+    storage: &mut dyn Storage,
+    env: &Env,
+    address: &Addr,
+    event: ReputationEvent,
+) -> StdResult<()> {
+    let config = DAO_CONFIG.load(storage)?.reputation_config;
+    let mut reputation = reputation_at(storage, address, &config, env.block.time.seconds())?;
+    let points = match event {
+        ReputationEvent::PaperPublished => {
+            reputation.papers_published += 1;
+            config.paper_points
+        }
+        ReputationEvent::CitationReceived => {
+            reputation.citations_received += 1;
+            config.citation_points
+        }
+        ReputationEvent::ReviewCompleted => {
+            reputation.reviews_completed += 1;
+            config.review_points
+        }
+        ReputationEvent::ProposalPassed => {
+            reputation.proposals_passed += 1;
+            config.proposal_points
+        }
+    };
+    reputation.score = reputation.score.saturating_add(points);
+    REPUTATION.save(storage, address, &reputation)?;
+    update_leaderboard(storage, address, &config, env.block.time.seconds())
+}
+
+/// 把地址加入排行榜候选，超出上限时淘汰当前分数最低的地址
+fn update_leaderboard(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    config: &ReputationConfig,
+    now: u64,
+) -> StdResult<()> {
+    let mut leaderboard = REPUTATION_LEADERBOARD
+        .may_load(storage)?
+        .unwrap_or_default();
+    if leaderboard.contains(address) {
+        return Ok(());
+    }
+    leaderboard.push(address.clone());
+    if leaderboard.len() > MAX_LEADERBOARD_SIZE {
+        let mut lowest = (0, u64::MAX);
+        for (index, member) in leaderboard.iter().enumerate() {
+            let score = reputation_at(storage, member, config, now)?.score;
+            if score < lowest.1 {
+                lowest = (index, score);
+            }
+        }
+        leaderboard.remove(lowest.0);
+    }
+    REPUTATION_LEADERBOARD.save(storage, &leaderboard)
+}
+
+/// 验证投票揭示期不超过上限
+pub fn validate_reveal_period(reveal_period: u64) -> Result<(), ContractError> {
+    if reveal_period > MAX_REVEAL_PERIOD {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Reveal period cannot exceed 30 days",
+        )));
+    }
+    Ok(())
+}
+
+/// 验证承诺为十六进制编码的 sha256 哈希，统一转为小写
+pub fn normalize_commitment(commitment: &str) -> Result<String, ContractError> {
+    let commitment = commitment.trim().to_ascii_lowercase();
+    if commitment.len() != 64 || !commitment.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ContractError::InvalidCommitment {});
+    }
+    Ok(commitment)
+}
+
+fn sha256_hex(preimage: &str) -> String {
+    hex::encode(Sha256::digest(preimage.as_bytes()))
+}
+
+/// 投票选项在承诺原文中的名称，与消息中使用的 snake_case 命名一致
+fn vote_choice_name(choice: &VoteChoice) -> &'static str {
+    match choice {
+        VoteChoice::Yes => "yes",
+        VoteChoice::No => "no",
+        VoteChoice::NoWithVeto => "no_with_veto",
+        VoteChoice::Abstain => "abstain",
+    }
+}
+
+/// 评审建议在承诺原文中的名称，与消息中使用的 snake_case 命名一致
+fn review_recommendation_name(recommendation: &ReviewRecommendation) -> &'static str {
+    match recommendation {
+        ReviewRecommendation::Accept => "accept",
+        ReviewRecommendation::MinorRevision => "minor_revision",
+        ReviewRecommendation::MajorRevision => "major_revision",
+        ReviewRecommendation::Reject => "reject",
+    }
+}
+
+/// 计算投票承诺，承诺中包含投票者地址，防止直接复制他人的承诺
+/// 原文为 UTF-8 字符串 "{proposal_id}:{voter}:{choice}:{salt}"，`proposal_id` 为十进制，
+/// `choice` 为 yes / no / no_with_veto / abstain，承诺为 sha256 的小写十六进制编码
+pub fn vote_commitment(proposal_id: u64, voter: &Addr, choice: &VoteChoice, salt: &str) -> String {
+    sha256_hex(&format!(
+        "{}:{}:{}:{}",
+        proposal_id,
+        voter,
+        vote_choice_name(choice),
+        salt
+    ))
+}
+
+/// 计算评审承诺
+/// 原文为 UTF-8 字符串 "{proposal_id}:{reviewer}:{score}:{recommendation}:{report_ipfs_hash}:{salt}"，
+/// 数字为十进制，`recommendation` 为 accept / minor_revision / major_revision / reject
+pub fn review_commitment(
+    proposal_id: u64,
+    reviewer: &Addr,
+    score: u8,
+    recommendation: &ReviewRecommendation,
+    report_ipfs_hash: &str,
+    salt: &str,
+) -> String {
+    sha256_hex(&format!(
+        "{}:{}:{}:{}:{}:{}",
+        proposal_id,
+        reviewer,
+        score,
+        review_recommendation_name(recommendation),
+        report_ipfs_hash,
+        salt
+    ))
+}
+
+/// 检查评审阶段是否已超过截止时间，超时的提案视为过期
+pub fn is_review_expired(env: &Env, proposal: &Proposal) -> bool {
+    proposal.status == ProposalStatus::UnderReview
+        && proposal
+            .review_deadline
+            .is_some_and(|deadline| env.block.time.seconds() > deadline)
+}
+
+/// 检查当前揭示期是否已结束
+pub fn is_reveal_period_over(env: &Env, proposal: &Proposal) -> bool {
+    proposal
+        .reveal_end
+        .is_some_and(|reveal_end| env.block.time.seconds() > reveal_end)
+}
+
+/// 验证提案处于指定的揭示阶段且揭示期未结束
+pub fn ensure_in_reveal_phase(
+    env: &Env,
+    proposal: &Proposal,
+    phase: ProposalStatus,
+) -> Result<(), ContractError> {
+    if proposal.status != phase {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Proposal is not in the reveal phase",
+        )));
+    }
+    if is_reveal_period_over(env, proposal) {
+        return Err(ContractError::RevealPeriodEnded {});
+    }
+    Ok(())
+}
+
+/// 验证提案使用公开投票，提交-揭示投票的提案只能提交承诺
+pub fn ensure_open_voting(proposal: &Proposal) -> Result<(), ContractError> {
+    if proposal.vote_reveal_period > 0 {
+        return Err(ContractError::CommitmentRequired {});
+    }
+    Ok(())
+}
+
+/// 验证提案处于提交-揭示投票的提交期
+pub fn ensure_can_commit_vote(env: &Env, proposal: &Proposal) -> Result<(), ContractError> {
+    if proposal.vote_reveal_period == 0 {
+        return Err(ContractError::CommitRevealDisabled {});
+    }
+    ensure_can_vote_on_proposal(env, proposal)
+}
+
+/// 按时间推进提案的投票阶段，返回投票是否已结束、可以确定结果
+/// 公开投票在投票期结束后结束；提交-揭示投票在提交期结束后进入揭示期，揭示期结束后才确定结果
+pub fn advance_vote_phase(env: &Env, proposal: &mut Proposal) -> Result<bool, ContractError> {
+    let now = env.block.time.seconds();
+    match proposal.status {
+        ProposalStatus::Active if proposal.vote_reveal_period == 0 => Ok(now > proposal.voting_end),
+        ProposalStatus::Active => {
+            if now <= proposal.voting_end {
+                return Ok(false);
+            }
+            validate_proposal_status_transition(&proposal.status, &ProposalStatus::VoteReveal)?;
+            proposal.status = ProposalStatus::VoteReveal;
+            proposal.reveal_end = Some(proposal.voting_end + proposal.vote_reveal_period);
+            Ok(is_reveal_period_over(env, proposal))
+        }
+        ProposalStatus::VoteReveal => Ok(is_reveal_period_over(env, proposal)),
+        _ => Ok(false),
+    }
+}
+
+/// 验证执行时间锁不超过上限
+pub fn validate_execution_delay(execution_delay: u64) -> Result<(), ContractError> {
+    if execution_delay > MAX_EXECUTION_DELAY {
+        return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+            "Execution delay cannot exceed 30 days",
+        )));
+    }
+    Ok(())
+}
+
+/// 已投票数（含弃权）
+pub fn total_voted(vote_count: &VoteCount) -> u64 {
+    vote_count.yes + vote_count.no + vote_count.no_with_veto + vote_count.abstain
+}
+
+/// 检查参与投票的比例（含弃权）是否达到法定人数
+pub fn quorum_reached(vote_count: &VoteCount, quorum: u64) -> bool {
+    total_voted(vote_count) * 100 >= vote_count.total_eligible * quorum
+}
+
+/// 检查赞成票占 yes/(yes+no) 的比例是否达到通过阈值，NoWithVeto 计为反对
+pub fn threshold_reached(yes: u64, against: u64, approval_threshold: u64) -> bool {
+    yes > 0 && yes * 100 >= (yes + against) * approval_threshold
+}
+
+/// 按提案快照中的委托关系返回从成员开始的委托链（包含成员自身）
+pub fn delegation_chain(
+    storage: &dyn Storage,
+    proposal_id: u64,
+    member: &str,
+) -> StdResult<Vec<String>> {
+    let mut chain = vec![member.to_string()];
+    while let Some(delegate) =
+        PROPOSAL_DELEGATIONS.may_load(storage, (proposal_id, chain.last().unwrap()))?
+    {
+        // 委托时已阻止循环，这里只做防御
+        if chain.iter().any(|m| m == delegate.as_str()) {
+            break;
+        }
+        chain.push(delegate.into_string());
+    }
+    Ok(chain)
+}
+
+/// 在快照时一次性解析委托：每个成员的权重计入其委托链上的所有成员
+/// 此时还没有人投票，委托人的权重会一直传递到链的末端
+pub fn resolve_delegated_power(storage: &mut dyn Storage, proposal_id: u64) -> StdResult<()> {
+    let voters: Vec<(String, u64)> = PROPOSAL_VOTERS
+        .prefix(proposal_id)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (voter, _) in &voters {
+        PROPOSAL_POWER.save(storage, (proposal_id, voter), &0)?;
+    }
+    for (voter, power) in voters {
+        for holder in delegation_chain(storage, proposal_id, &voter)? {
+            PROPOSAL_POWER.update(storage, (proposal_id, &holder), |held| -> StdResult<_> {
+                Ok(held.unwrap_or(0) + power)
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// 票数统计中某个选择对应的计数
+fn choice_tally<'a>(vote_count: &'a mut VoteCount, choice: &VoteChoice) -> &'a mut u64 {
+    match choice {
+        VoteChoice::Yes => &mut vote_count.yes,
+        VoteChoice::No => &mut vote_count.no,
+        VoteChoice::NoWithVeto => &mut vote_count.no_with_veto,
+        VoteChoice::Abstain => &mut vote_count.abstain,
+    }
+}
+
+/// 记录一票后增量更新票数，返回更新后的统计
+/// 投票者持有的权重计入其选择；首次投票时这部分权重不再沿委托链向上传递，
+/// 从链上各成员持有的权重以及第一个已投票成员的选择中移出，因此委托人直接投票会覆盖受托人
+pub fn apply_vote(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    voter: &str,
+    previous: Option<&VoteChoice>,
+    choice: &VoteChoice,
+) -> StdResult<VoteCount> {
+    let mut vote_count = VOTE_COUNTS.load(storage, proposal_id)?;
+    let power = PROPOSAL_POWER
+        .may_load(storage, (proposal_id, voter))?
+        .unwrap_or(0);
+
+    match previous {
+        Some(previous) => {
+            let tally = choice_tally(&mut vote_count, previous);
+            *tally = tally.saturating_sub(power);
+        }
+        None => {
+            for holder in delegation_chain(storage, proposal_id, voter)?
+                .iter()
+                .skip(1)
+            {
+                PROPOSAL_POWER.update(storage, (proposal_id, holder), |held| -> StdResult<_> {
+                    Ok(held.unwrap_or(0).saturating_sub(power))
+                })?;
+                if let Some(vote) = VOTES.may_load(storage, (proposal_id, holder))? {
+                    let tally = choice_tally(&mut vote_count, &vote.choice);
+                    *tally = tally.saturating_sub(power);
+                    break;
+                }
+            }
+        }
+    }
+    *choice_tally(&mut vote_count, choice) += power;
+
+    VOTE_COUNTS.save(storage, proposal_id, &vote_count)?;
+    Ok(vote_count)
+}
+
+/// 按各投票者持有的投票权重新统计提案票数，只在重置投票或缺少缓存统计时使用
+pub fn tally_votes(storage: &dyn Storage, proposal_id: u64) -> StdResult<VoteCount> {
+    let total_eligible = PROPOSAL_VOTERS
+        .prefix(proposal_id)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, power)| power))
+        .sum::<StdResult<u64>>()?;
+
+    let mut vote_count = VoteCount {
+        yes: 0,
+        no: 0,
+        no_with_veto: 0,
+        abstain: 0,
+        total_eligible,
+    };
+    let votes: Vec<(String, Vote)> = VOTES
+        .prefix(proposal_id)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (voter, vote) in votes {
+        let power = PROPOSAL_POWER
+            .may_load(storage, (proposal_id, &voter))?
+            .unwrap_or(0);
+        *choice_tally(&mut vote_count, &vote.choice) += power;
+    }
+    Ok(vote_count)
+}
 
+/// 验证法定人数百分比
+pub fn validate_quorum(quorum: u64) -> Result<(), ContractError> {
+    if quorum == 0 || quorum > 100 {
+        return Err(ContractError::InvalidQuorum {});
+    }
     Ok(())
 }
 
@@ -99,8 +619,30 @@ pub fn validate_proposal_status_transition(
         // 从 Active 可以转换到任何其他状态
         (ProposalStatus::Active, _) => Ok(()),
 
-        // 从 Passed 只能转换到 Executed
+        // 从 Passed 只能转换到 Executed，或在时间锁期间被否决
         (ProposalStatus::Passed, ProposalStatus::Executed) => Ok(()),
+        (ProposalStatus::Passed, ProposalStatus::Vetoed) => Ok(()),
+
+        // 执行时有消息失败，由 reply 标记
+        (ProposalStatus::Executed, ProposalStatus::Failed) => Ok(()),
+
+        // 评审完成后进入投票，评审期间提案者可以撤回
+        (ProposalStatus::UnderReview, ProposalStatus::Active) => Ok(()),
+        (ProposalStatus::UnderReview, ProposalStatus::Withdrawn) => Ok(()),
+        // 评审截止时间已过仍未进入投票
+        (ProposalStatus::UnderReview, ProposalStatus::Expired) => Ok(()),
+
+        // 盲审：承诺齐备后进入揭示期，揭示的评审不足时回到评审阶段
+        (ProposalStatus::UnderReview, ProposalStatus::ReviewReveal) => Ok(()),
+        (ProposalStatus::ReviewReveal, ProposalStatus::Active) => Ok(()),
+        (ProposalStatus::ReviewReveal, ProposalStatus::UnderReview) => Ok(()),
+        (ProposalStatus::ReviewReveal, ProposalStatus::Withdrawn) => Ok(()),
+
+        // 投票揭示期结束后确定结果，揭示期内仍可被否决
+        (ProposalStatus::VoteReveal, ProposalStatus::Passed) => Ok(()),
+        (ProposalStatus::VoteReveal, ProposalStatus::Rejected) => Ok(()),
+        (ProposalStatus::VoteReveal, ProposalStatus::Expired) => Ok(()),
+        (ProposalStatus::VoteReveal, ProposalStatus::Vetoed) => Ok(()),
 
         // 其他转换都是无效的
         _ => Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
@@ -111,21 +653,9 @@ pub fn validate_proposal_status_transition(
 
 /// 获取当前 DAO 成员总数
 pub fn get_dao_member_count(deps: Deps) -> StdResult<u64> {
-    let members: StdResult<Vec<_>> = DAO_MEMBERS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .filter_map(|item| match item {
-            Ok((_, is_member)) => {
-                if is_member {
-                    Some(Ok(()))
-                } else {
-                    None
-                }
-            }
-            Err(e) => Some(Err(e)),
-        })
-        .collect();
-
-    members.map(|m| m.len() as u64)
+    Ok(DAO_MEMBERS
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .count() as u64)
 }
 
 /// 验证 DAO 配置参数的有效性
@@ -159,6 +689,170 @@ pub fn validate_dao_config(
     Ok(())
 }
 
+/// 验证接受的代币列表：不能为空，不能包含空字符串或重复项
+pub fn validate_denoms(denoms: &[String]) -> Result<(), ContractError> {
+    if denoms.is_empty() {
+        return Err(ContractError::InvalidDenoms {});
+    }
+
+    for (i, denom) in denoms.iter().enumerate() {
+        if denom.trim().is_empty() || denoms[..i].contains(denom) {
+            return Err(ContractError::InvalidDenoms {});
+        }
+    }
+
+    Ok(())
+}
+
+/// 验证费用配置：各项分成比例不能超过 100%
+pub fn validate_fee_config(config: &FeeConfig) -> Result<(), ContractError> {
+    if config.citation_dao_share_bps > BPS_DENOMINATOR
+        || config.access_protocol_fee_bps > BPS_DENOMINATOR
+    {
+        return Err(ContractError::InvalidFeeConfig {});
+    }
+    Ok(())
+}
+
+/// 按基点计算份额
+pub fn bps_share(amount: Uint128, bps: u64) -> Uint128 {
+    amount.multiply_ratio(bps, BPS_DENOMINATOR)
+}
+
+/// 检查代币是否在接受列表中
+pub fn ensure_accepted_denom(deps: Deps, denom: &str) -> Result<(), ContractError> {
+    let config = PAYMENT_CONFIG.load(deps.storage)?;
+    if !config.accepted_denoms.iter().any(|d| d == denom) {
+        return Err(ContractError::UnsupportedDenom {
+            denom: denom.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// 获取引用费用的计价代币
+pub fn citation_denom(deps: Deps) -> StdResult<String> {
+    let config = FEE_CONFIG.load(deps.storage)?;
+    Ok(config.base_citation_fee.denom)
+}
+
+/// 检查新的接受列表没有移除仍被上架数据项价格或引用费用使用的代币
+pub fn ensure_denoms_removable(deps: Deps, new_denoms: &[String]) -> Result<(), ContractError> {
+    let current = PAYMENT_CONFIG.load(deps.storage)?;
+    let citation_denom = citation_denom(deps)?;
+    for denom in current.accepted_denoms {
+        if new_denoms.contains(&denom) {
+            continue;
+        }
+        let listed = LISTED_DENOMS.may_load(deps.storage, &denom)?.unwrap_or(0);
+        if listed > 0 || denom == citation_denom {
+            return Err(ContractError::DenomInUse { denom });
+        }
+    }
+    Ok(())
+}
+
+/// 数据项价格不为 0 时按计价代币记入或移出上架计数
+pub fn update_listed_denoms(
+    storage: &mut dyn Storage,
+    item: &DataItem,
+    listed: bool,
+) -> StdResult<()> {
+    if item.price.is_zero() {
+        return Ok(());
+    }
+    let count = LISTED_DENOMS.may_load(storage, &item.denom)?.unwrap_or(0);
+    let count = if listed {
+        count + 1
+    } else {
+        count.saturating_sub(1)
+    };
+    if count == 0 {
+        LISTED_DENOMS.remove(storage, &item.denom);
+    } else {
+        LISTED_DENOMS.save(storage, &item.denom, &count)?;
+    }
+    Ok(())
+}
+
+/// 获取附带的指定代币金额，附带其他代币时返回错误而不是默默收下
+pub fn must_pay(info: &MessageInfo, denom: &str) -> Result<Uint128, ContractError> {
+    let mut payment = Uint128::zero();
+    for coin in &info.funds {
+        if coin.denom == denom {
+            payment += coin.amount;
+        } else if !coin.amount.is_zero() {
+            return Err(ContractError::UnexpectedFunds {
+                denom: coin.denom.clone(),
+            });
+        }
+    }
+    Ok(payment)
+}
+
+/// 验证调用没有附带任何资金
+pub fn nonpayable(info: &MessageInfo) -> Result<(), ContractError> {
+    if let Some(coin) = info.funds.iter().find(|coin| !coin.amount.is_zero()) {
+        return Err(ContractError::UnexpectedFunds {
+            denom: coin.denom.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// 从附带的资金中扣出指定金额，返回剩余资金，用于同一调用中支付多笔款项
+pub fn take_payment(info: &MessageInfo, payment: &Coin) -> Result<MessageInfo, ContractError> {
+    let mut remaining = payment.amount;
+    let mut funds = vec![];
+    for coin in &info.funds {
+        if coin.denom == payment.denom && !remaining.is_zero() {
+            let taken = coin.amount.min(remaining);
+            remaining -= taken;
+            if coin.amount > taken {
+                funds.push(Coin {
+                    denom: coin.denom.clone(),
+                    amount: coin.amount - taken,
+                });
+            }
+        } else {
+            funds.push(coin.clone());
+        }
+    }
+    if !remaining.is_zero() {
+        return Err(ContractError::InsufficientPayment {});
+    }
+    Ok(MessageInfo {
+        sender: info.sender.clone(),
+        funds,
+    })
+}
+
+/// 规范化 DOI：去除 `https://doi.org/` 等解析前缀并转为小写
+/// DOI 不区分大小写，规范化后的值用作唯一索引
+pub fn normalize_doi(doi: &str) -> Result<String, ContractError> {
+    let mut normalized = doi.trim().to_lowercase();
+    for prefix in [
+        "https://doi.org/",
+        "http://doi.org/",
+        "https://dx.doi.org/",
+        "http://dx.doi.org/",
+        "doi:",
+    ] {
+        if let Some(rest) = normalized.strip_prefix(prefix) {
+            normalized = rest.trim().to_string();
+            break;
+        }
+    }
+
+    // 基本格式检查：前缀/后缀
+    match normalized.split_once('/') {
+        Some((prefix, suffix)) if !prefix.is_empty() && !suffix.is_empty() => Ok(normalized),
+        _ => Err(ContractError::InvalidDoi {
+            doi: doi.to_string(),
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,19 +866,19 @@ mod tests {
         let addr = Addr::unchecked("member1");
 
         // 测试不存在的成员
-        assert_eq!(is_dao_member(deps.as_ref(), &addr).unwrap(), false);
+        assert!(!is_dao_member(deps.as_ref(), &addr).unwrap());
 
         // 添加成员
         DAO_MEMBERS
-            .save(deps.as_mut().storage, addr.as_str(), &true)
+            .save(deps.as_mut().storage, addr.as_str(), &3)
             .unwrap();
-        assert_eq!(is_dao_member(deps.as_ref(), &addr).unwrap(), true);
+        assert!(is_dao_member(deps.as_ref(), &addr).unwrap());
+        assert_eq!(3, member_weight(deps.as_ref(), &addr).unwrap());
 
         // 移除成员
-        DAO_MEMBERS
-            .save(deps.as_mut().storage, addr.as_str(), &false)
-            .unwrap();
-        assert_eq!(is_dao_member(deps.as_ref(), &addr).unwrap(), false);
+        DAO_MEMBERS.remove(deps.as_mut().storage, addr.as_str());
+        assert!(!is_dao_member(deps.as_ref(), &addr).unwrap());
+        assert_eq!(0, member_weight(deps.as_ref(), &addr).unwrap());
     }
 
     #[test]
@@ -197,7 +891,7 @@ mod tests {
 
         // 添加成员
         DAO_MEMBERS
-            .save(deps.as_mut().storage, addr.as_str(), &true)
+            .save(deps.as_mut().storage, addr.as_str(), &1)
             .unwrap();
         assert!(ensure_dao_member(deps.as_ref(), &addr).is_ok());
     }
@@ -217,6 +911,14 @@ mod tests {
             voting_end: 1100, // 未过期
             status: ProposalStatus::Active,
             execution_data: None,
+            deposit: None,
+            snapshot_height: 0,
+            executable_at: None,
+            execution_deadline: None,
+            review_deadline: None,
+            vote_reveal_period: 0,
+            reveal_end: None,
+            review_bounty: None,
         };
 
         let expired_proposal = Proposal {
@@ -229,6 +931,14 @@ mod tests {
             voting_end: 900, // 已过期
             status: ProposalStatus::Active,
             execution_data: None,
+            deposit: None,
+            snapshot_height: 0,
+            executable_at: None,
+            execution_deadline: None,
+            review_deadline: None,
+            vote_reveal_period: 0,
+            reveal_end: None,
+            review_bounty: None,
         };
 
         // 测试活跃提案
@@ -255,6 +965,14 @@ mod tests {
             voting_end: 1100,
             status: ProposalStatus::Passed,
             execution_data: None,
+            deposit: None,
+            snapshot_height: 0,
+            executable_at: None,
+            execution_deadline: None,
+            review_deadline: None,
+            vote_reveal_period: 0,
+            reveal_end: None,
+            review_bounty: None,
         };
 
         let executed_proposal = Proposal {
@@ -267,6 +985,14 @@ mod tests {
             voting_end: 1100,
             status: ProposalStatus::Executed,
             execution_data: None,
+            deposit: None,
+            snapshot_height: 0,
+            executable_at: None,
+            execution_deadline: None,
+            review_deadline: None,
+            vote_reveal_period: 0,
+            reveal_end: None,
+            review_bounty: None,
         };
 
         // 测试可执行的提案
@@ -274,6 +1000,43 @@ mod tests {
 
         // 测试已执行的提案
         assert!(ensure_can_execute_proposal(&env, &executed_proposal).is_err());
+
+        // 否决只能发生在投票中或通过后
+        for status in [ProposalStatus::Active, ProposalStatus::Passed] {
+            assert!(validate_proposal_status_transition(&status, &ProposalStatus::Vetoed).is_ok());
+        }
+        for status in [ProposalStatus::Executed, ProposalStatus::Vetoed] {
+            assert!(validate_proposal_status_transition(&status, &ProposalStatus::Vetoed).is_err());
+        }
+        assert!(validate_proposal_status_transition(
+            &ProposalStatus::Vetoed,
+            &ProposalStatus::Executed
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_quorum_and_threshold() {
+        let vote_count = VoteCount {
+            yes: 2,
+            no: 1,
+            no_with_veto: 0,
+            abstain: 1,
+            total_eligible: 10,
+        };
+
+        // 4/10 参与，弃权计入法定人数
+        assert!(quorum_reached(&vote_count, 40));
+        assert!(!quorum_reached(&vote_count, 41));
+
+        // 赞成 2/3
+        assert!(threshold_reached(2, 1, 66));
+        assert!(!threshold_reached(2, 1, 67));
+        assert!(!threshold_reached(0, 0, 51));
+
+        assert!(validate_quorum(33).is_ok());
+        assert!(validate_quorum(0).is_err());
+        assert!(validate_quorum(101).is_err());
     }
 
     #[test]
@@ -291,6 +1054,169 @@ mod tests {
         // 测试无效最小成员数
         assert!(validate_dao_config(None, None, Some(0)).is_err());
     }
+
+    #[test]
+    fn test_denom_validation_and_payment() {
+        let inj = "inj".to_string();
+        let usdc = "uusdc".to_string();
+
+        assert!(validate_denoms(&[inj.clone(), usdc.clone()]).is_ok());
+        assert!(validate_denoms(&[]).is_err());
+        assert!(validate_denoms(&[inj.clone(), inj.clone()]).is_err());
+        assert!(validate_denoms(&[" ".to_string()]).is_err());
+
+        let info = cosmwasm_std::testing::mock_info("payer", &cosmwasm_std::coins(100, "inj"));
+        assert_eq!(must_pay(&info, "inj").unwrap(), Uint128::new(100));
+        assert!(must_pay(&info, "uusdc").is_err());
+        assert!(nonpayable(&info).is_err());
+
+        let info = cosmwasm_std::testing::mock_info("payer", &[]);
+        assert_eq!(must_pay(&info, "inj").unwrap(), Uint128::zero());
+        assert!(nonpayable(&info).is_ok());
+    }
+
+    #[test]
+    fn test_normalize_doi() {
+        let expected = "10.1000/abc.def";
+        assert_eq!(normalize_doi("10.1000/ABC.def").unwrap(), expected);
+        assert_eq!(
+            normalize_doi(" https://doi.org/10.1000/abc.DEF ").unwrap(),
+            expected
+        );
+        assert_eq!(
+            normalize_doi("HTTP://DX.DOI.ORG/10.1000/abc.def").unwrap(),
+            expected
+        );
+        assert_eq!(normalize_doi("doi:10.1000/abc.def").unwrap(), expected);
+
+        assert!(normalize_doi("").is_err());
+        assert!(normalize_doi("not-a-doi").is_err());
+        assert!(normalize_doi("https://doi.org/10.1000/").is_err());
+    }
+
+    #[test]
+    fn test_commit_reveal_vote_phases() {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(1000);
+
+        let mut proposal = Proposal {
+            id: 1,
+            proposer: Addr::unchecked("proposer"),
+            proposal_type: ProposalType::ArticlePublication,
+            title: "Test".to_string(),
+            description: "Test".to_string(),
+            created_at: 900,
+            voting_end: 1100,
+            status: ProposalStatus::Active,
+            execution_data: None,
+            deposit: None,
+            snapshot_height: 0,
+            executable_at: None,
+            execution_deadline: None,
+            review_deadline: None,
+            vote_reveal_period: 100,
+            reveal_end: None,
+            review_bounty: None,
+        };
+
+        // 提交期内只能提交承诺
+        assert!(ensure_open_voting(&proposal).is_err());
+        assert!(ensure_can_commit_vote(&env, &proposal).is_ok());
+        assert!(!advance_vote_phase(&env, &mut proposal).unwrap());
+        assert_eq!(ProposalStatus::Active, proposal.status);
+
+        // 提交期结束进入揭示期，揭示期结束后投票结束
+        env.block.time = Timestamp::from_seconds(1150);
+        assert!(!advance_vote_phase(&env, &mut proposal).unwrap());
+        assert_eq!(ProposalStatus::VoteReveal, proposal.status);
+        assert_eq!(Some(1200), proposal.reveal_end);
+        assert!(ensure_can_commit_vote(&env, &proposal).is_err());
+        assert!(ensure_in_reveal_phase(&env, &proposal, ProposalStatus::VoteReveal).is_ok());
+
+        env.block.time = Timestamp::from_seconds(1201);
+        assert!(advance_vote_phase(&env, &mut proposal).unwrap());
+        assert!(ensure_in_reveal_phase(&env, &proposal, ProposalStatus::VoteReveal).is_err());
+
+        // 承诺与投票者、选择和盐值绑定
+        let voter = Addr::unchecked("voter");
+        let commitment = vote_commitment(1, &voter, &VoteChoice::Yes, "salt");
+        assert_eq!(
+            commitment,
+            normalize_commitment(&commitment.to_uppercase()).unwrap()
+        );
+        assert_ne!(
+            commitment,
+            vote_commitment(1, &voter, &VoteChoice::No, "salt")
+        );
+        assert_ne!(
+            commitment,
+            vote_commitment(1, &Addr::unchecked("other"), &VoteChoice::Yes, "salt")
+        );
+        assert!(normalize_commitment("not-a-hash").is_err());
+
+        // 承诺原文使用固定的 snake_case 名称，不依赖 Debug 输出
+        assert_eq!(sha256_hex("1:voter:yes:salt"), commitment);
+        assert_eq!(
+            sha256_hex("1:voter:no_with_veto:salt"),
+            vote_commitment(1, &voter, &VoteChoice::NoWithVeto, "salt")
+        );
+        assert_eq!(
+            sha256_hex("1:reviewer:8:minor_revision:QmReport:salt"),
+            review_commitment(
+                1,
+                &Addr::unchecked("reviewer"),
+                8,
+                &ReviewRecommendation::MinorRevision,
+                "QmReport",
+                "salt"
+            )
+        );
+    }
+
+    #[test]
+    fn test_decayed_score() {
+        // 不衰减
+        assert_eq!(100, decayed_score(100, 1_000_000, 0));
+        // 每个半衰期减半，半衰期内线性插值
+        assert_eq!(100, decayed_score(100, 0, 1000));
+        assert_eq!(75, decayed_score(100, 500, 1000));
+        assert_eq!(50, decayed_score(100, 1000, 1000));
+        assert_eq!(25, decayed_score(100, 2000, 1000));
+        assert_eq!(0, decayed_score(u64::MAX, 64_000, 1000));
+    }
+
+    #[test]
+    fn test_leaderboard_keeps_highest_scores() {
+        let mut deps = mock_dependencies();
+        let config = default_reputation_config();
+        let now = mock_env().block.time.seconds();
+        let save = |storage: &mut dyn Storage, address: &Addr, score: u64| {
+            let mut reputation = reputation_at(storage, address, &config, now).unwrap();
+            reputation.score = score;
+            REPUTATION.save(storage, address, &reputation).unwrap();
+        };
+
+        // 候选已满时淘汰分数最低的地址
+        for i in 0..MAX_LEADERBOARD_SIZE {
+            let address = Addr::unchecked(format!("member{}", i));
+            save(deps.as_mut().storage, &address, i as u64 + 1);
+            update_leaderboard(deps.as_mut().storage, &address, &config, now).unwrap();
+        }
+        let newcomer = Addr::unchecked("newcomer");
+        save(deps.as_mut().storage, &newcomer, 1000);
+        update_leaderboard(deps.as_mut().storage, &newcomer, &config, now).unwrap();
+        let leaderboard = REPUTATION_LEADERBOARD.load(deps.as_ref().storage).unwrap();
+        assert_eq!(MAX_LEADERBOARD_SIZE, leaderboard.len());
+        assert!(leaderboard.contains(&newcomer));
+        assert!(!leaderboard.contains(&Addr::unchecked("member0")));
+
+        // 分数低于所有候选的地址不进入排行榜
+        let idle = Addr::unchecked("idle");
+        update_leaderboard(deps.as_mut().storage, &idle, &config, now).unwrap();
+        let leaderboard = REPUTATION_LEADERBOARD.load(deps.as_ref().storage).unwrap();
+        assert_eq!(MAX_LEADERBOARD_SIZE, leaderboard.len());
+        assert!(!leaderboard.contains(&idle));
+    }
 }
 /// 自动检查并更新过期提案的状态
 /// 这个函数会检查提案是否过期，如果过期则更新状态为 Expired
@@ -374,19 +1300,15 @@ pub fn validate_status_transition_timing(
     new_status: &ProposalStatus,
 ) -> Result<(), ContractError> {
     match new_status {
-        ProposalStatus::Executed => {
-            // 只有在投票期限内才能执行
-            if is_proposal_expired(env, proposal) {
-                return Err(ContractError::ProposalExpired {});
-            }
+        // 只有在投票期限内才能执行
+        ProposalStatus::Executed if is_proposal_expired(env, proposal) => {
+            return Err(ContractError::ProposalExpired {});
         }
-        ProposalStatus::Expired => {
-            // 只有在投票期限过后才能标记为过期
-            if !is_proposal_expired(env, proposal) {
-                return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
-                    "Cannot mark proposal as expired before voting period ends",
-                )));
-            }
+        // 只有在投票期限过后才能标记为过期
+        ProposalStatus::Expired if !is_proposal_expired(env, proposal) => {
+            return Err(ContractError::Std(cosmwasm_std::StdError::generic_err(
+                "Cannot mark proposal as expired before voting period ends",
+            )));
         }
         _ => {} // 其他状态转换不需要时间约束
     }
@@ -416,6 +1338,14 @@ mod additional_tests {
             voting_end: 1100, // 已过期
             status: ProposalStatus::Active,
             execution_data: None,
+            deposit: None,
+            snapshot_height: 0,
+            executable_at: None,
+            execution_deadline: None,
+            review_deadline: None,
+            vote_reveal_period: 0,
+            reveal_end: None,
+            review_bounty: None,
         };
 
         let deps = mock_dependencies();
@@ -451,6 +1381,14 @@ mod additional_tests {
             voting_end: 1200,
             status: ProposalStatus::Active,
             execution_data: None,
+            deposit: None,
+            snapshot_height: 0,
+            executable_at: None,
+            execution_deadline: None,
+            review_deadline: None,
+            vote_reveal_period: 0,
+            reveal_end: None,
+            review_bounty: None,
         };
 
         // 测试投票窗口检查
@@ -479,6 +1417,14 @@ mod additional_tests {
             voting_end: 1100, // 未过期
             status: ProposalStatus::Active,
             execution_data: None,
+            deposit: None,
+            snapshot_height: 0,
+            executable_at: None,
+            execution_deadline: None,
+            review_deadline: None,
+            vote_reveal_period: 0,
+            reveal_end: None,
+            review_bounty: None,
         };
 
         let expired_proposal = Proposal {
@@ -491,6 +1437,14 @@ mod additional_tests {
             voting_end: 900, // 已过期
             status: ProposalStatus::Active,
             execution_data: None,
+            deposit: None,
+            snapshot_height: 0,
+            executable_at: None,
+            execution_deadline: None,
+            review_deadline: None,
+            vote_reveal_period: 0,
+            reveal_end: None,
+            review_bounty: None,
         };
 
         // 测试在有效期内执行
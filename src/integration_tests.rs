@@ -1,110 +1,4085 @@
 #[cfg(test)]
 mod tests {
-    use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, VoteChoice};
-    use crate::state::{DAO_CONFIG, DAO_MEMBERS, PROPOSAL_COUNTER};
+    use crate::msg::{
+        AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ContractOwnerResponse,
+        DelegatorsResponse, EffectivePowerResponse, ExecuteMsg, Expiration, FeeConfigResponse,
+        InstantiateMsg, NumTokensResponse, OperatorsResponse, OutstandingDepositsResponse,
+        OwnerOfResponse, ProposalResponse, QueryMsg, TokensByOwnerResponse, TokensResponse,
+        TreasuryBalanceResponse, VoteChoice, VoteCountResponse, VotingPowerResponse,
+    };
+    use crate::state::{
+        ACCESS_CONTROLS, AUTHORIZED_USERS, DAO_CONFIG, DAO_MEMBERS, DATA_ITEMS, DATA_VERSIONS,
+        PROPOSAL_COUNTER, TOKEN_OWNERS,
+    };
     use crate::{
         contracts::{execute::*, instantiate::*, query::*},
         ContractError,
     };
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{coins, from_json};
+    use cosmwasm_std::{coin, coins, from_json, Addr, BankMsg, Coin, CosmosMsg, Uint128};
+
+    // ===== 基础功能测试 =====
+
+    #[test]
+    fn proper_initialization() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &coins(1000, "earth"));
+
+        let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(0, res.messages.len());
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::ContractInfo {}).unwrap();
+        let value: crate::msg::ContractInfoResponse = from_json(&res).unwrap();
+        assert_eq!("Research Data NFT", value.name);
+        assert_eq!("RDN", value.symbol);
+    }
+
+    #[test]
+    fn dao_initialization() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &coins(1000, "earth"));
+
+        let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // Verify DAO initialization
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "dao_initialized" && attr.value == "true"));
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "first_dao_member" && attr.value == "creator"));
+
+        // Verify creator is DAO member
+        let weight = DAO_MEMBERS.load(deps.as_ref().storage, "creator").unwrap();
+        assert_eq!(1, weight);
+
+        // Verify DAO config defaults
+        let dao_config = DAO_CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(604800, dao_config.voting_period); // 7 days
+        assert_eq!(51, dao_config.approval_threshold); // 51%
+        assert_eq!(1, dao_config.min_members);
+
+        let proposal_counter = PROPOSAL_COUNTER.load(deps.as_ref().storage).unwrap();
+        assert_eq!(0, proposal_counter);
+    }
+
+    // ===== NFT基础功能测试 =====
+
+    #[test]
+    fn create_data_item() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &coins(2, "token"));
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let info = mock_info("creator", &[]);
+        let msg = ExecuteMsg::CreateDataItem {
+            ipfs_hash: "QmTest".to_string(),
+            price: Uint128::new(1000),
+            denom: None,
+            is_public: false,
+            metadata_uri: "https://example.com/metadata.json".to_string(),
+        };
+        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(0, res.messages.len());
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetDataItem {
+                token_id: "0".to_string(),
+            },
+        )
+        .unwrap();
+        let data_item: crate::msg::DataItem = from_json(&res).unwrap();
+        assert_eq!("QmTest", data_item.ipfs_hash);
+        assert_eq!(Uint128::new(1000), data_item.price);
+        assert!(!data_item.is_public);
+    }
+
+    #[test]
+    fn update_data_item_terms_and_purchase_access() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let info = mock_info("lab", &[]);
+        let msg = ExecuteMsg::CreateDataItem {
+            ipfs_hash: "QmDataset".to_string(),
+            price: Uint128::zero(),
+            denom: None,
+            is_public: false,
+            metadata_uri: "https://example.com/dataset.json".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // 价格为 0 的私有数据不对外出售
+        let info = mock_info("buyer", &coins(500, "inj"));
+        let msg = ExecuteMsg::RequestAccess {
+            token_id: "0".to_string(),
+        };
+        let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::NotAuthorized {}));
+
+        // 只有所有者可以修改条款
+        let msg = ExecuteMsg::UpdateDataItemTerms {
+            token_id: "0".to_string(),
+            price: Some(Uint128::new(500)),
+            denom: None,
+            is_public: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("buyer", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotAuthorized {}));
+        execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+
+        // 付款不足
+        let info = mock_info("buyer", &coins(100, "inj"));
+        let msg = ExecuteMsg::RequestAccess {
+            token_id: "0".to_string(),
+        };
+        let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::InsufficientPayment {}));
+
+        // 足额付款后获得读取权限，费用转给所有者
+        let info = mock_info("buyer", &coins(500, "inj"));
+        let msg = ExecuteMsg::RequestAccess {
+            token_id: "0".to_string(),
+        };
+        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(1, res.messages.len());
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::CheckAccessLevel {
+                token_id: "0".to_string(),
+                user: "buyer".to_string(),
+            },
+        )
+        .unwrap();
+        let level: crate::msg::AccessLevel = from_json(&res).unwrap();
+        assert_eq!(crate::msg::AccessLevel::Read, level);
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetDataItem {
+                token_id: "0".to_string(),
+            },
+        )
+        .unwrap();
+        let data_item: crate::msg::DataItem = from_json(&res).unwrap();
+        assert_eq!(Uint128::new(500), data_item.total_earned);
+
+        // 已获得权限的用户再次请求无需付款
+        let info = mock_info("buyer", &[]);
+        let msg = ExecuteMsg::RequestAccess {
+            token_id: "0".to_string(),
+        };
+        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(0, res.messages.len());
+
+        // 冻结后不能再修改条款
+        let msg = ExecuteMsg::FreezeData {
+            token_id: "0".to_string(),
+            freeze: true,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+        let msg = ExecuteMsg::UpdateDataItemTerms {
+            token_id: "0".to_string(),
+            price: Some(Uint128::new(1)),
+            denom: None,
+            is_public: Some(true),
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::DataFrozen {}));
+    }
+
+    #[test]
+    fn payment_denoms_are_configurable() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["uusdc".to_string(), "inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // 不接受的计价代币
+        let msg = ExecuteMsg::CreateDataItem {
+            ipfs_hash: "QmDataset".to_string(),
+            price: Uint128::new(300),
+            denom: Some("uatom".to_string()),
+            is_public: false,
+            metadata_uri: "https://example.com/dataset.json".to_string(),
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::UnsupportedDenom { .. }));
+
+        let msg = ExecuteMsg::CreateDataItem {
+            ipfs_hash: "QmDataset".to_string(),
+            price: Uint128::new(300),
+            denom: Some("inj".to_string()),
+            is_public: false,
+            metadata_uri: "https://example.com/dataset.json".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+
+        // 附带额外代币时拒绝，而不是默默收下
+        let info = mock_info("buyer", &[Coin::new(300, "inj"), Coin::new(10, "uusdc")]);
+        let msg = ExecuteMsg::RequestAccess {
+            token_id: "0".to_string(),
+        };
+        let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::UnexpectedFunds { .. }));
+
+        let info = mock_info("buyer", &coins(300, "inj"));
+        let msg = ExecuteMsg::RequestAccess {
+            token_id: "0".to_string(),
+        };
+        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "lab".to_string(),
+                amount: coins(300, "inj"),
+            })
+        );
+
+        // 引用费用默认使用第一个接受的代币计价
+        let msg = ExecuteMsg::CreatePaperItem {
+            ipfs_hash: "QmPaper".to_string(),
+            doi: "10.1000/denom.paper".to_string(),
+            metadata_uri: "https://example.com/paper.json".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::CitePaper {
+            paper_id: "1".to_string(),
+            citing_paper_id: None,
+        };
+        let info = mock_info("citer", &coins(100_000, "inj"));
+        let err = execute(deps.as_mut(), mock_env(), info, msg.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::UnexpectedFunds { .. }));
+        let info = mock_info("citer", &coins(100_000, "uusdc"));
+        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(1, res.messages.len());
+
+        // 仍被上架数据项或引用费用使用的代币不能移除
+        let msg = ExecuteMsg::UpdateAcceptedDenoms {
+            denoms: vec!["uusdc".to_string()],
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::DenomInUse { denom } if denom == "inj"));
+
+        let msg = ExecuteMsg::UpdateAcceptedDenoms {
+            denoms: vec!["inj".to_string()],
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("lab", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotDaoMember {}));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DenomInUse { denom } if denom == "uusdc"));
+
+        // 引用费用改为 inj 计价后才能通过 DAO 提案移除 uusdc
+        let fees = ExecuteMsg::UpdateFees {
+            base_citation_fee: Some(coin(100_000, "inj")),
+            citation_dao_share_bps: None,
+            access_protocol_fee_bps: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), fees).unwrap();
+        let vote = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), vote).unwrap();
+        let exec = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), exec).unwrap();
+
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 1,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 1 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetPaymentConfig {}).unwrap();
+        let config: crate::msg::PaymentConfigResponse = from_json(&res).unwrap();
+        assert_eq!(vec!["inj".to_string()], config.config.accepted_denoms);
+    }
+
+    #[test]
+    fn cw721_approvals_and_queries() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let _res = instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::CreateDataItem {
+            ipfs_hash: "QmDataset".to_string(),
+            price: Uint128::new(10),
+            denom: None,
+            is_public: true,
+            metadata_uri: "https://example.com/dataset.json".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+
+        // 不能设置已过期的批准
+        let env = mock_env();
+        let msg = ExecuteMsg::Approve {
+            spender: "spender".to_string(),
+            token_id: "0".to_string(),
+            expires: Some(Expiration::AtHeight(env.block.height)),
+        };
+        let err = execute(deps.as_mut(), env.clone(), mock_info("lab", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::Expired {}));
+
+        let msg = ExecuteMsg::Approve {
+            spender: "spender".to_string(),
+            token_id: "0".to_string(),
+            expires: Some(Expiration::AtHeight(env.block.height + 10)),
+        };
+        execute(deps.as_mut(), env.clone(), mock_info("lab", &[]), msg).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::OwnerOf {
+                token_id: "0".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+        let owner: OwnerOfResponse = from_json(&res).unwrap();
+        assert_eq!("lab", owner.owner);
+        assert_eq!(1, owner.approvals.len());
+
+        // 过期后批准失效，include_expired 时仍可查询
+        let mut later = env.clone();
+        later.block.height += 20;
+        let res = query(
+            deps.as_ref(),
+            later.clone(),
+            QueryMsg::Approvals {
+                token_id: "0".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+        let approvals: ApprovalsResponse = from_json(&res).unwrap();
+        assert!(approvals.approvals.is_empty());
+        let res = query(
+            deps.as_ref(),
+            later.clone(),
+            QueryMsg::Approval {
+                token_id: "0".to_string(),
+                spender: "spender".to_string(),
+                include_expired: Some(true),
+            },
+        )
+        .unwrap();
+        let approval: ApprovalResponse = from_json(&res).unwrap();
+        assert_eq!("spender", approval.approval.spender);
+
+        let msg = ExecuteMsg::TransferNft {
+            recipient: "spender".to_string(),
+            token_id: "0".to_string(),
+        };
+        let err = execute(deps.as_mut(), later, mock_info("spender", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::NotAuthorized {}));
+
+        // 撤销批准
+        let msg = ExecuteMsg::Revoke {
+            spender: "spender".to_string(),
+            token_id: "0".to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), mock_info("lab", &[]), msg).unwrap();
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Approval {
+                token_id: "0".to_string(),
+                spender: "spender".to_string(),
+                include_expired: Some(true),
+            },
+        );
+        assert!(res.is_err());
+
+        // 操作员可以代为转移
+        let msg = ExecuteMsg::ApproveAll {
+            operator: "operator".to_string(),
+            expires: None,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info("lab", &[]), msg).unwrap();
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::AllOperators {
+                owner: "lab".to_string(),
+                include_expired: None,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let operators: OperatorsResponse = from_json(&res).unwrap();
+        assert_eq!("operator", operators.operators[0].spender);
+
+        let msg = ExecuteMsg::TransferNft {
+            recipient: "collector".to_string(),
+            token_id: "0".to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), mock_info("operator", &[]), msg).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Tokens {
+                owner: "collector".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let tokens: TokensResponse = from_json(&res).unwrap();
+        assert_eq!(vec!["0".to_string()], tokens.tokens);
+
+        let res = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::AllNftInfo {
+                token_id: "0".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+        let info: AllNftInfoResponse<crate::msg::DataItem> = from_json(&res).unwrap();
+        assert_eq!("collector", info.access.owner);
+        assert_eq!(
+            Some("https://example.com/dataset.json".to_string()),
+            info.info.token_uri
+        );
+        assert_eq!("QmDataset", info.info.extension.ipfs_hash);
+    }
+
+    #[test]
+    fn retire_and_burn_data_items() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        for ipfs_hash in ["QmDataset0", "QmDataset1"] {
+            let msg = ExecuteMsg::CreateDataItem {
+                ipfs_hash: ipfs_hash.to_string(),
+                price: Uint128::new(500),
+                denom: None,
+                is_public: false,
+                metadata_uri: "https://example.com/dataset.json".to_string(),
+            };
+            execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+        }
+
+        // 退役后停止出售访问权限，恢复后可继续购买
+        let msg = ExecuteMsg::RetireData {
+            token_id: "0".to_string(),
+            retire: true,
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("buyer", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotAuthorized {}));
+        execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+
+        let request = ExecuteMsg::RequestAccess {
+            token_id: "0".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("buyer", &coins(500, "inj")),
+            request.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DataRetired {}));
+
+        let msg = ExecuteMsg::RetireData {
+            token_id: "0".to_string(),
+            retire: false,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("buyer", &coins(500, "inj")),
+            request,
+        )
+        .unwrap();
+
+        // 销毁后清除所有相关存储
+        let msg = ExecuteMsg::Burn {
+            token_id: "0".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("buyer", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotAuthorized {}));
+        execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+
+        let storage = deps.as_ref().storage;
+        assert!(!TOKEN_OWNERS.has(storage, "0"));
+        assert!(!DATA_ITEMS.has(storage, "0"));
+        assert!(!DATA_VERSIONS.has(storage, "0"));
+        assert!(!ACCESS_CONTROLS.has(storage, ("0", "buyer")));
+        assert!(!AUTHORIZED_USERS.has(storage, ("0", &Addr::unchecked("buyer"))));
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::NumTokens {}).unwrap();
+        let count: NumTokensResponse = from_json(&res).unwrap();
+        assert_eq!(1, count.count);
+
+        // 已冻结的数据需要 DAO 批准才能销毁
+        let msg = ExecuteMsg::FreezeData {
+            token_id: "1".to_string(),
+            freeze: true,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::Burn {
+            token_id: "1".to_string(),
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::BurnRequiresDaoApproval {}));
+
+        let msg = ExecuteMsg::SubmitBurnProposal {
+            token_id: "1".to_string(),
+            title: "Burn dataset".to_string(),
+            description: "Dataset was withdrawn".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("buyer", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotAuthorized {}));
+        execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        let res = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "action" && attr.value == "token_burned"));
+
+        assert!(!TOKEN_OWNERS.has(deps.as_ref().storage, "1"));
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::NumTokens {}).unwrap();
+        let count: NumTokensResponse = from_json(&res).unwrap();
+        assert_eq!(0, count.count);
+    }
+
+    #[test]
+    fn tokens_by_owner_follows_transfers_and_burns() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        for (creator, ipfs_hash) in [
+            ("lab", "QmDataset0"),
+            ("other", "QmDataset1"),
+            ("lab", "QmDataset2"),
+            ("lab", "QmDataset3"),
+        ] {
+            let msg = ExecuteMsg::CreateDataItem {
+                ipfs_hash: ipfs_hash.to_string(),
+                price: Uint128::zero(),
+                denom: None,
+                is_public: true,
+                metadata_uri: "https://example.com/dataset.json".to_string(),
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(creator, &[]), msg).unwrap();
+        }
+
+        let msg = ExecuteMsg::TransferNft {
+            recipient: "collector".to_string(),
+            token_id: "2".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+        let msg = ExecuteMsg::Burn {
+            token_id: "3".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("lab", &[]), msg).unwrap();
+
+        let tokens_by_owner = |deps: cosmwasm_std::Deps, owner: &str, start_after: Option<&str>| {
+            let res = query(
+                deps,
+                mock_env(),
+                QueryMsg::TokensByOwner {
+                    owner: owner.to_string(),
+                    start_after: start_after.map(|s| s.to_string()),
+                    limit: Some(1),
+                },
+            )
+            .unwrap();
+            from_json::<TokensByOwnerResponse>(&res).unwrap().tokens
+        };
+
+        let page = tokens_by_owner(deps.as_ref(), "lab", None);
+        assert_eq!(1, page.len());
+        assert_eq!("0", page[0].token_id);
+        assert_eq!("QmDataset0", page[0].data_item.ipfs_hash);
+        assert!(tokens_by_owner(deps.as_ref(), "lab", Some("0")).is_empty());
+
+        let page = tokens_by_owner(deps.as_ref(), "collector", None);
+        assert_eq!(1, page.len());
+        assert_eq!("2", page[0].token_id);
+        assert_eq!("collector", page[0].data_item.owner);
+    }
+
+    #[test]
+    fn doi_registry_is_unique_and_resolvable() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let msg = ExecuteMsg::CreatePaperItem {
+            ipfs_hash: "QmPaper".to_string(),
+            doi: "https://doi.org/10.1000/Paper.One".to_string(),
+            metadata_uri: "https://example.com/paper.json".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetPaperDoi {
+                paper_id: "0".to_string(),
+            },
+        )
+        .unwrap();
+        let doi: String = from_json(&res).unwrap();
+        assert_eq!("10.1000/paper.one", doi);
+
+        // 所有创建途径都检查 DOI 唯一性
+        let msg = ExecuteMsg::CreatePaperItem {
+            ipfs_hash: "QmPaperCopy".to_string(),
+            doi: "10.1000/PAPER.ONE".to_string(),
+            metadata_uri: "https://example.com/paper.json".to_string(),
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("other", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateDoi { .. }));
+
+        let msg = ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmPaperCopy".to_string(),
+            doi: "doi:10.1000/paper.one".to_string(),
+            metadata_uri: "https://example.com/paper.json".to_string(),
+            title: "Duplicate".to_string(),
+            description: "Same DOI".to_string(),
+            review_bounty: None,
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("other", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateDoi { .. }));
+
+        let msg = ExecuteMsg::CreatePaperItem {
+            ipfs_hash: "QmPaper".to_string(),
+            doi: "not-a-doi".to_string(),
+            metadata_uri: "https://example.com/paper.json".to_string(),
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidDoi { .. }));
+
+        // 多次修正得到不同的 DOI
+        for _ in 0..2 {
+            let msg = ExecuteMsg::SubmitCorrection {
+                original_paper_id: "0".to_string(),
+                new_ipfs_hash: "QmPaperFixed".to_string(),
+            };
+            execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap();
+        }
+
+        let paper_by_doi = |deps: cosmwasm_std::Deps, doi: &str| {
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::GetPaperByDoi {
+                    doi: doi.to_string(),
+                },
+            )
+            .map(|res| from_json::<crate::msg::TokenInfoResponse>(&res).unwrap())
+        };
+        assert_eq!(
+            "0",
+            paper_by_doi(deps.as_ref(), "HTTPS://DOI.ORG/10.1000/paper.one")
+                .unwrap()
+                .token_id
+        );
+        assert_eq!(
+            "1",
+            paper_by_doi(deps.as_ref(), "10.1000/paper.one-v2")
+                .unwrap()
+                .token_id
+        );
+        assert_eq!(
+            "2",
+            paper_by_doi(deps.as_ref(), "10.1000/paper.one-v3")
+                .unwrap()
+                .token_id
+        );
+
+        // 销毁后释放 DOI
+        let msg = ExecuteMsg::Burn {
+            token_id: "2".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap();
+        assert!(paper_by_doi(deps.as_ref(), "10.1000/paper.one-v3").is_err());
+    }
+
+    #[test]
+    fn proposal_deposits_are_refunded_or_slashed() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // 通过配置提案开启押金和垃圾提案没收
+        let msg = ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: None,
+            proposal_deposit: Some(Coin::new(100, "inj")),
+            slash_spam_deposits: Some(true),
+            execution_delay: None,
+            veto_council: None,
+            review_config: None,
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        // 还未开启押金时附带资金会被拒绝
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &coins(100, "inj")),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::UnexpectedFunds { .. }));
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        // 无押金的提案不能取回押金
+        let msg = ExecuteMsg::ClaimDeposit { proposal_id: 0 };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::NoDeposit {}));
+
+        let article = |doi: &str| ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmArticle".to_string(),
+            doi: doi.to_string(),
+            metadata_uri: "https://example.com/article.json".to_string(),
+            title: "Article".to_string(),
+            description: "Article for review".to_string(),
+            review_bounty: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &coins(50, "inj")),
+            article("10.1000/deposit.one"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InsufficientPayment {}));
+        // 多付的押金被拒绝，不会留在合约中
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &coins(150, "inj")),
+            article("10.1000/deposit.one"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ExcessPayment { .. }));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &coins(100, "inj")),
+            article("10.1000/deposit.one"),
+        )
+        .unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetOutstandingDeposits {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let outstanding: OutstandingDepositsResponse = from_json(&res).unwrap();
+        assert_eq!(1, outstanding.deposits.len());
+        assert_eq!(1, outstanding.deposits[0].proposal_id);
+        assert_eq!(Coin::new(100, "inj"), outstanding.deposits[0].amount);
+        assert!(!outstanding.deposits[0].voting_ended);
+
+        let msg = ExecuteMsg::ClaimDeposit { proposal_id: 1 };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::VotingPeriodActive {}));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        // 提案通过后退还押金
+        let vote = ExecuteMsg::VoteOnProposal {
+            proposal_id: 1,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), vote).unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &[]),
+            msg.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "author".to_string(),
+                amount: coins(100, "inj"),
+            })
+        );
+        let err = execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::DepositAlreadyRefunded {}));
+
+        // 被认定为垃圾提案的押金归 DAO 所有
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("spammer", &coins(100, "inj")),
+            article("10.1000/deposit.two"),
+        )
+        .unwrap();
+        let vote = ExecuteMsg::VoteOnProposal {
+            proposal_id: 2,
+            choice: VoteChoice::NoWithVeto,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), vote).unwrap();
+        let msg = ExecuteMsg::ClaimDeposit { proposal_id: 2 };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("spammer", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::DepositSlashed {}));
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::TreasuryBalance {}).unwrap();
+        let treasury: TreasuryBalanceResponse = from_json(&res).unwrap();
+        assert_eq!(vec![Coin::new(100, "inj")], treasury.balances);
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetOutstandingDeposits {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let outstanding: OutstandingDepositsResponse = from_json(&res).unwrap();
+        assert!(outstanding.deposits.is_empty());
+    }
+
+    #[test]
+    fn dao_revenue_is_held_in_treasury_and_spent_by_proposal() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::CreatePaperItem {
+            ipfs_hash: "QmPaper".to_string(),
+            doi: "10.1000/treasury.paper".to_string(),
+            metadata_uri: "https://example.com/paper.json".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap();
+
+        // 引用费中 DAO 的 5% 留在合约金库，不再转给合约所有者
+        let msg = ExecuteMsg::CitePaper {
+            paper_id: "0".to_string(),
+            citing_paper_id: None,
+        };
+        let info = mock_info("citer", &coins(100_000, "inj"));
+        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: "author".to_string(),
+                amount: coins(95_000, "inj"),
+            })],
+            res.messages.into_iter().map(|m| m.msg).collect::<Vec<_>>()
+        );
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::TreasuryBalance {}).unwrap();
+        let treasury: TreasuryBalanceResponse = from_json(&res).unwrap();
+        assert_eq!(vec![Coin::new(5_000, "inj")], treasury.balances);
+
+        let spend = |amount: u128| ExecuteMsg::SubmitTreasurySpendProposal {
+            recipient: "lab".to_string(),
+            amount: Coin::new(amount, "inj"),
+            title: "Fund lab".to_string(),
+            description: "Grant from the treasury".to_string(),
+        };
+
+        // 只有 DAO 成员可以提交，且金额不能超过金库余额
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("outsider", &[]),
+            spend(1_000),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotDaoMember {}));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            spend(10_000),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InsufficientTreasuryFunds { .. }
+        ));
+
+        // 两个提案都在余额之内提交，但合计超出余额
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            spend(3_000),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            spend(3_000),
+        )
+        .unwrap();
+        for proposal_id in 0..2 {
+            let vote = ExecuteMsg::VoteOnProposal {
+                proposal_id,
+                choice: VoteChoice::Yes,
+            };
+            execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), vote).unwrap();
+        }
+
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        let res = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "lab".to_string(),
+                amount: coins(3_000, "inj"),
+            })
+        );
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::TreasuryBalance {}).unwrap();
+        let treasury: TreasuryBalanceResponse = from_json(&res).unwrap();
+        assert_eq!(vec![Coin::new(2_000, "inj")], treasury.balances);
+
+        // 执行时重新检查余额
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 1 };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InsufficientTreasuryFunds { .. }
+        ));
+    }
+
+    #[test]
+    fn fee_schedule_changes_only_through_proposal() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetFeeConfig {}).unwrap();
+        let fees: FeeConfigResponse = from_json(&res).unwrap();
+        assert_eq!(coin(100_000, "inj"), fees.config.base_citation_fee);
+        assert_eq!(500, fees.config.citation_dao_share_bps);
+        assert_eq!(0, fees.config.access_protocol_fee_bps);
+
+        let update = |dao_bps: u64| ExecuteMsg::UpdateFees {
+            base_citation_fee: Some(coin(200_000, "inj")),
+            citation_dao_share_bps: Some(dao_bps),
+            access_protocol_fee_bps: Some(2_000),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("outsider", &[]),
+            update(1_000),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotDaoMember {}));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            update(10_001),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidFeeConfig {}));
+
+        // 提案通过并执行前费用保持不变
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            update(1_000),
+        )
+        .unwrap();
+        let vote = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), vote).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetBaseCitationFee {}).unwrap();
+        let fee: crate::msg::BaseCitationFeeResponse = from_json(&res).unwrap();
+        assert_eq!(Uint128::new(100_000), fee.fee);
+        assert_eq!("inj", fee.denom);
+
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetFeeConfig {}).unwrap();
+        let fees: FeeConfigResponse = from_json(&res).unwrap();
+        assert_eq!(coin(200_000, "inj"), fees.config.base_citation_fee);
+        assert_eq!(1_000, fees.config.citation_dao_share_bps);
+        assert_eq!(2_000, fees.config.access_protocol_fee_bps);
+
+        // 访问费用按协议分成拆分
+        let msg = ExecuteMsg::CreateDataItem {
+            ipfs_hash: "QmData".to_string(),
+            price: Uint128::new(1_000),
+            denom: None,
+            is_public: false,
+            metadata_uri: "https://example.com/data.json".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+        let msg = ExecuteMsg::RequestAccess {
+            token_id: "0".to_string(),
+        };
+        let info = mock_info("buyer", &coins(1_000, "inj"));
+        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "owner".to_string(),
+                amount: coins(800, "inj"),
+            })
+        );
+
+        // 引用费用使用新的最低费用和分成比例
+        let msg = ExecuteMsg::CreatePaperItem {
+            ipfs_hash: "QmPaper".to_string(),
+            doi: "10.1000/fees.paper".to_string(),
+            metadata_uri: "https://example.com/paper.json".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap();
+        let msg = ExecuteMsg::CitePaper {
+            paper_id: "1".to_string(),
+            citing_paper_id: None,
+        };
+        let info = mock_info("citer", &coins(100_000, "inj"));
+        let err = execute(deps.as_mut(), mock_env(), info, msg.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::InsufficientPayment {}));
+        let info = mock_info("citer", &coins(200_000, "inj"));
+        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "author".to_string(),
+                amount: coins(180_000, "inj"),
+            })
+        );
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::TreasuryBalance {}).unwrap();
+        let treasury: TreasuryBalanceResponse = from_json(&res).unwrap();
+        assert_eq!(vec![Coin::new(20_200, "inj")], treasury.balances);
+    }
+
+    #[test]
+    fn ownership_transfer_and_renunciation() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        // 只有所有者可以提名，提名不能已经过期
+        let propose = |new_owner: &str, expiry: Option<Expiration>| ExecuteMsg::ProposeNewOwner {
+            new_owner: new_owner.to_string(),
+            expiry,
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("intruder", &[]),
+            propose("intruder", None),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotAuthorized {}));
+        let expired = Expiration::AtHeight(mock_env().block.height);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            propose("lab", Some(expired)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Expired {}));
+
+        // 提名过期后不能再接受
+        let expiry = Expiration::AtHeight(mock_env().block.height + 10);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            propose("lab", Some(expiry)),
+        )
+        .unwrap();
+        let mut later = mock_env();
+        later.block.height += 10;
+        let err = execute(
+            deps.as_mut(),
+            later,
+            mock_info("lab", &[]),
+            ExecuteMsg::AcceptOwnership {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Expired {}));
+
+        // 所有权在接受前不变，只有被提名者可以接受
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            propose("lab", None),
+        )
+        .unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetContractOwner {}).unwrap();
+        let owner: ContractOwnerResponse = from_json(&res).unwrap();
+        assert_eq!(Addr::unchecked("creator"), owner.owner);
+        assert_eq!(Addr::unchecked("lab"), owner.pending_owner.unwrap().owner);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            ExecuteMsg::AcceptOwnership {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotAuthorized {}));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("lab", &[]),
+            ExecuteMsg::AcceptOwnership {},
+        )
+        .unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetContractOwner {}).unwrap();
+        let owner: ContractOwnerResponse = from_json(&res).unwrap();
+        assert_eq!(Addr::unchecked("lab"), owner.owner);
+        assert_eq!(None, owner.pending_owner);
+
+        // 放弃所有权后不能再提名，合约完全由 DAO 治理，查询返回合约自身地址
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("lab", &[]),
+            propose("steward", None),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("lab", &[]),
+            ExecuteMsg::RenounceOwnership {},
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("lab", &[]),
+            propose("lab", None),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::OwnershipRenounced {}));
+        // 放弃所有权同时取消了未接受的提名
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("steward", &[]),
+            ExecuteMsg::AcceptOwnership {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoPendingOwner {}));
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::GetContractOwner {}).unwrap();
+        let owner: ContractOwnerResponse = from_json(&res).unwrap();
+        assert_eq!(mock_env().contract.address, owner.owner);
+        assert_eq!(None, owner.pending_owner);
+    }
+
+    #[test]
+    fn quorum_and_threshold_are_evaluated_separately() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+        for member in ["member1", "member2", "member3", "member4"] {
+            DAO_MEMBERS.save(deps.as_mut().storage, member, &1).unwrap();
+        }
+
+        let config_proposal = |quorum: u64| ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: Some(quorum),
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: None,
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        let vote = |proposal_id: u64, choice: VoteChoice| ExecuteMsg::VoteOnProposal {
+            proposal_id,
+            choice,
+        };
+        for quorum in [40, 50, 60] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("creator", &[]),
+                config_proposal(quorum),
+            )
+            .unwrap();
+        }
+
+        // 提案 0：1 票赞成 + 1 票弃权，弃权计入法定人数（2/5 >= 33%）
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(0, VoteChoice::Yes),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member1", &[]),
+            vote(0, VoteChoice::Abstain),
+        )
+        .unwrap();
+
+        // 提案 1：只有 1 票，未达到法定人数
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(1, VoteChoice::Yes),
+        )
+        .unwrap();
+
+        // 投票期内不能结算
+        let msg = ExecuteMsg::FinalizeProposal { proposal_id: 0 };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::VotingPeriodActive {}));
+
+        // 提案 2：剩余成员全部赞成也无法达到 51%，提前拒绝
+        for (voter, choice) in [
+            ("creator", VoteChoice::Yes),
+            ("member1", VoteChoice::No),
+            ("member2", VoteChoice::No),
+        ] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(voter, &[]),
+                vote(2, choice),
+            )
+            .unwrap();
+        }
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member3", &[]),
+            vote(2, VoteChoice::NoWithVeto),
+        )
+        .unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "proposal_status" && attr.value == "Rejected"));
+
+        // 投票期结束后结算
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(604800 + 1);
+        let res = execute(deps.as_mut(), env.clone(), mock_info("anyone", &[]), msg).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "proposal_status" && attr.value == "Passed"));
+
+        let msg = ExecuteMsg::FinalizeProposal { proposal_id: 1 };
+        let res = execute(deps.as_mut(), env.clone(), mock_info("anyone", &[]), msg).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "proposal_status" && attr.value == "Expired"));
+
+        // 投票期结束后通过的提案仍可执行
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), env, mock_info("creator", &[]), msg).unwrap();
+        let config = DAO_CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(40, config.quorum);
+    }
+
+    #[test]
+    fn member_weights_drive_voting_power() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let add_member = |weight: Option<u64>| ExecuteMsg::SubmitMemberProposal {
+            member_address: "editor".to_string(),
+            action: crate::msg::MemberAction::Add,
+            weight,
+            title: "Add editor".to_string(),
+            description: "Senior editor".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            add_member(Some(0)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidMemberWeight {}));
+
+        // 添加权重为 3 的编辑
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            add_member(Some(3)),
+        )
+        .unwrap();
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        let power = |deps: cosmwasm_std::Deps, member: &str| {
+            let res = query(
+                deps,
+                mock_env(),
+                QueryMsg::GetMemberVotingPower {
+                    member: member.to_string(),
+                },
+            )
+            .unwrap();
+            from_json::<VotingPowerResponse>(res).unwrap()
+        };
+        assert_eq!(3, power(deps.as_ref(), "editor").power);
+        assert_eq!(0, power(deps.as_ref(), "stranger").power);
+
+        // 编辑的 3 票压过创建者的 1 票反对
+        let msg = ExecuteMsg::SubmitMemberWeightProposal {
+            member_address: "creator".to_string(),
+            weight: 2,
+            title: "Promote creator".to_string(),
+            description: "Increase weight".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("editor", &[]), msg).unwrap();
+        for (voter, choice) in [("creator", VoteChoice::No), ("editor", VoteChoice::Yes)] {
+            let msg = ExecuteMsg::VoteOnProposal {
+                proposal_id: 1,
+                choice,
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(voter, &[]), msg).unwrap();
+        }
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetVoteCount { proposal_id: 1 },
+        )
+        .unwrap();
+        let count: VoteCountResponse = from_json(res).unwrap();
+        assert_eq!(3, count.vote_count.yes);
+        assert_eq!(1, count.vote_count.no);
+        assert_eq!(4, count.vote_count.total_eligible);
+
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 1 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let creator = power(deps.as_ref(), "creator");
+        assert!(creator.is_member);
+        assert_eq!(2, creator.power);
+    }
+
+    #[test]
+    fn delegated_power_follows_snapshot_and_direct_votes() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+        for member in ["member1", "member2", "member3", "member4"] {
+            DAO_MEMBERS.save(deps.as_mut().storage, member, &1).unwrap();
+        }
+
+        let delegate = |to: &str| ExecuteMsg::Delegate { to: to.to_string() };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member1", &[]),
+            delegate("member1"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::CannotDelegateToSelf {}));
+
+        // member3 -> member1 -> member2
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member1", &[]),
+            delegate("member2"),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member3", &[]),
+            delegate("member1"),
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member2", &[]),
+            delegate("member3"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DelegationCycle {}));
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetDelegators {
+                member: "member1".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let delegators: DelegatorsResponse = from_json(res).unwrap();
+        assert_eq!(Some(Addr::unchecked("member2")), delegators.delegate);
+        assert_eq!(vec![Addr::unchecked("member3")], delegators.delegators);
+
+        let msg = ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: Some(50),
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: None,
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        // 提案创建后取消委托不影响快照
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member1", &[]),
+            ExecuteMsg::Undelegate {},
+        )
+        .unwrap();
+
+        let effective_power = |deps: cosmwasm_std::Deps, member: &str| {
+            let res = query(
+                deps,
+                mock_env(),
+                QueryMsg::GetEffectivePower {
+                    proposal_id: 0,
+                    member: member.to_string(),
+                },
+            )
+            .unwrap();
+            from_json::<EffectivePowerResponse>(res).unwrap()
+        };
+        let power = effective_power(deps.as_ref(), "member2");
+        assert_eq!(1, power.own_power);
+        assert_eq!(2, power.delegated_power);
+
+        let vote_count = |deps: cosmwasm_std::Deps| {
+            let res = query(deps, mock_env(), QueryMsg::GetVoteCount { proposal_id: 0 }).unwrap();
+            from_json::<VoteCountResponse>(res).unwrap().vote_count
+        };
+        let vote = |choice: VoteChoice| ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice,
+        };
+        // member1 直接投票覆盖受托人 member2，并代表 member3 投票
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member1", &[]),
+            vote(VoteChoice::No),
+        )
+        .unwrap();
+        let count = vote_count(deps.as_ref());
+        assert_eq!(2, count.no);
+        assert_eq!(5, count.total_eligible);
+        assert_eq!(0, effective_power(deps.as_ref(), "member2").delegated_power);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member2", &[]),
+            vote(VoteChoice::Yes),
+        )
+        .unwrap();
+        let count = vote_count(deps.as_ref());
+        assert_eq!(1, count.yes);
+        assert_eq!(2, count.no);
+
+        // member3 直接投票后，其权重从 member1 的选择中移出
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member3", &[]),
+            vote(VoteChoice::Abstain),
+        )
+        .unwrap();
+        let count = vote_count(deps.as_ref());
+        assert_eq!(1, count.no);
+        assert_eq!(1, count.abstain);
+        assert_eq!(0, effective_power(deps.as_ref(), "member1").delegated_power);
+
+        // 改票只移动投票者当前持有的权重
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member1", &[]),
+            vote(VoteChoice::Yes),
+        )
+        .unwrap();
+        let count = vote_count(deps.as_ref());
+        assert_eq!(2, count.yes);
+        assert_eq!(0, count.no);
+        assert_eq!(1, count.abstain);
+    }
+
+    #[test]
+    fn passed_proposals_wait_for_execution_delay() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // 设置 1 天的执行时间锁，当前没有时间锁，通过后立即执行
+        let msg = ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: None,
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: Some(86400),
+            veto_council: None,
+            review_config: None,
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let vote = |proposal_id: u64| ExecuteMsg::VoteOnProposal {
+            proposal_id,
+            choice: VoteChoice::Yes,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(0),
+        )
+        .unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        assert_eq!(
+            86400,
+            DAO_CONFIG
+                .load(deps.as_ref().storage)
+                .unwrap()
+                .execution_delay
+        );
+
+        // 文章提案通过后不再自动执行
+        let msg = ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmTimelocked".to_string(),
+            doi: "10.1000/timelock".to_string(),
+            metadata_uri: "https://example.com/article.json".to_string(),
+            title: "Timelocked article".to_string(),
+            description: "Publish after delay".to_string(),
+            review_bounty: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(1),
+        )
+        .unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "proposal_status" && attr.value == "Passed"));
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetProposal { proposal_id: 1 },
+        )
+        .unwrap();
+        let proposal: ProposalResponse = from_json(res).unwrap();
+        let executable_at = mock_env().block.time.seconds() + 86400;
+        assert_eq!(Some(executable_at), proposal.proposal.executable_at);
+
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 1 };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::ProposalTimelocked { executable_at: at } if at == executable_at
+        ));
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        execute(deps.as_mut(), env, mock_info("creator", &[]), msg).unwrap();
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetPaperByDoi {
+                doi: "10.1000/timelock".to_string(),
+            },
+        )
+        .unwrap();
+        let paper: crate::msg::TokenInfoResponse = from_json(res).unwrap();
+        assert_eq!(Addr::unchecked("creator"), paper.owner);
+
+        // 时间锁结束后一个投票期内未执行的提案过期
+        let msg = ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmStale".to_string(),
+            doi: "10.1000/stale".to_string(),
+            metadata_uri: "https://example.com/article.json".to_string(),
+            title: "Stale article".to_string(),
+            description: "Never executed in time".to_string(),
+            review_bounty: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(2),
+        )
+        .unwrap();
+        let voting_period = DAO_CONFIG
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .voting_period;
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetProposal { proposal_id: 2 },
+        )
+        .unwrap();
+        let proposal: ProposalResponse = from_json(res).unwrap();
+        assert_eq!(
+            Some(executable_at + voting_period),
+            proposal.proposal.execution_deadline
+        );
+
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 2 };
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400 + voting_period + 1);
+        let err = execute(deps.as_mut(), env, mock_info("creator", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::ProposalExpired {}));
+    }
+
+    #[test]
+    fn veto_council_blocks_proposals_during_timelock() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let config = |council: Option<crate::msg::VetoCouncilMsg>| ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: None,
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: Some(86400),
+            veto_council: council,
+            review_config: None,
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        let council = |threshold: u64| crate::msg::VetoCouncilMsg {
+            name: "Ethics Board".to_string(),
+            members: vec!["guardian1".to_string(), "guardian2".to_string()],
+            threshold,
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            config(Some(council(3))),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidVetoCouncil {}));
+
+        // 设置需要 2 名成员否决的委员会
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            config(Some(council(2))),
+        )
+        .unwrap();
+        let vote = |proposal_id: u64| ExecuteMsg::VoteOnProposal {
+            proposal_id,
+            choice: VoteChoice::Yes,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(0),
+        )
+        .unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        // 通过后的配置提案在时间锁期间被否决
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            config(Some(council(1))),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(1),
+        )
+        .unwrap();
+
+        let veto = ExecuteMsg::VetoProposal {
+            proposal_id: 1,
+            reason: "Weakens oversight".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            veto.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotVetoCouncilMember {}));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("guardian1", &[]),
+            veto.clone(),
+        )
+        .unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "proposal_status" && attr.value == "Passed"));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("guardian1", &[]),
+            veto.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::AlreadyVetoed {}));
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info("guardian2", &[]), veto).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "proposal_status" && attr.value == "Vetoed"));
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(86400);
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 1 };
+        let err = execute(deps.as_mut(), env.clone(), mock_info("creator", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::ProposalDidNotPass {}));
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetVetoes { proposal_id: 1 },
+        )
+        .unwrap();
+        let vetoes: crate::msg::VetoesResponse = from_json(res).unwrap();
+        assert_eq!(2, vetoes.vetoes.len());
+        assert_eq!("Weakens oversight", vetoes.vetoes[0].reason);
+
+        // 时间锁结束后不能再否决
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("creator", &[]),
+            config(None),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("creator", &[]),
+            vote(2),
+        )
+        .unwrap();
+        let mut later = env.clone();
+        later.block.time = later.block.time.plus_seconds(86400);
+        let veto = ExecuteMsg::VetoProposal {
+            proposal_id: 2,
+            reason: "Too late".to_string(),
+        };
+        let err = execute(deps.as_mut(), later, mock_info("guardian1", &[]), veto).unwrap_err();
+        assert!(matches!(err, ContractError::CannotVetoProposal {}));
+
+        // 投票期结束但尚未确定结果的提案也不能再否决
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("creator", &[]),
+            config(None),
+        )
+        .unwrap();
+        let voting_period = DAO_CONFIG
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .voting_period;
+        let mut after_voting = env.clone();
+        after_voting.block.time = after_voting.block.time.plus_seconds(voting_period + 1);
+        let veto = ExecuteMsg::VetoProposal {
+            proposal_id: 3,
+            reason: "After voting ended".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            after_voting,
+            mock_info("guardian1", &[]),
+            veto,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::CannotVetoProposal {}));
+
+        // 委员会成员地址在保存前校验
+        let invalid = crate::msg::VetoCouncilMsg {
+            name: "Ethics Board".to_string(),
+            members: vec!["Guardian1".to_string()],
+            threshold: 1,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("creator", &[]),
+            config(Some(invalid)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Std(_)));
+    }
+
+    #[test]
+    fn article_proposals_need_reviews_before_voting() {
+        use crate::msg::{
+            ProposalStatus, ReviewAssignmentsResponse, ReviewConfigMsg, ReviewRecommendation,
+            ReviewsResponse,
+        };
+
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        let config = |editor: &str, required_reviews: u64| ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: None,
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: Some(ReviewConfigMsg {
+                editors: vec![editor.to_string()],
+                required_reviews,
+                review_period: 86400,
+                reveal_period: 0,
+            }),
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            config("editor", 0),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidReviewConfig {}));
+        // 编辑地址在保存前校验
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            config("Editor", 2),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Std(_)));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            config("editor", 2),
+        )
+        .unwrap();
+        let vote = |proposal_id: u64| ExecuteMsg::VoteOnProposal {
+            proposal_id,
+            choice: VoteChoice::Yes,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(0),
+        )
+        .unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        // 文章提案先进入评审阶段，不能投票
+        let msg = ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmReviewed".to_string(),
+            doi: "10.1000/reviewed.article".to_string(),
+            metadata_uri: "https://example.com/reviewed.json".to_string(),
+            title: "Reviewed Article".to_string(),
+            description: "Article that needs peer review".to_string(),
+            review_bounty: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap();
+        let status = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| {
+            let res = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GetProposal { proposal_id: 1 },
+            )
+            .unwrap();
+            let proposal: ProposalResponse = from_json(&res).unwrap();
+            proposal.proposal
+        };
+        assert_eq!(ProposalStatus::UnderReview, status(&deps).status);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(1),
+        )
+        .unwrap_err();
+
+        // 只有编辑可以分配评审人，提案者不能评审自己的文章
+        let assign = |reviewer: &str| ExecuteMsg::AssignReviewer {
+            proposal_id: 1,
+            reviewer: reviewer.to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            assign("reviewer1"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotEditor {}));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("editor", &[]),
+            assign("author"),
+        )
+        .unwrap_err();
+        for reviewer in ["reviewer1", "reviewer2", "reviewer3"] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("editor", &[]),
+                assign(reviewer),
+            )
+            .unwrap();
+        }
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("editor", &[]),
+            assign("reviewer1"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ReviewerAlreadyAssigned {}));
+
+        let assignments = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, reviewer: &str| {
+            let res = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GetReviewerAssignments {
+                    reviewer: reviewer.to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+            let response: ReviewAssignmentsResponse = from_json(&res).unwrap();
+            response.assignments
+        };
+        let pending = assignments(&deps, "reviewer1");
+        assert_eq!(1, pending.len());
+        assert_eq!(1, pending[0].proposal_id);
+        assert_eq!(mock_env().block.time.seconds() + 86400, pending[0].deadline);
+
+        let review = |score: u8| ExecuteMsg::SubmitReview {
+            proposal_id: 1,
+            score,
+            recommendation: ReviewRecommendation::MinorRevision,
+            report_ipfs_hash: "QmReport".to_string(),
+            salt: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("reviewer1", &[]),
+            review(11),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidReviewScore {}));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[]),
+            review(8),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotAssignedReviewer {}));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("reviewer1", &[]),
+            review(8),
+        )
+        .unwrap();
+        assert!(assignments(&deps, "reviewer1").is_empty());
+        assert_eq!(ProposalStatus::UnderReview, status(&deps).status);
+
+        // 超过截止时间的评审不被接受
+        let mut late = mock_env();
+        late.block.time = late.block.time.plus_seconds(86401);
+        let err = execute(deps.as_mut(), late, mock_info("reviewer3", &[]), review(5)).unwrap_err();
+        assert!(matches!(err, ContractError::ReviewDeadlinePassed {}));
+
+        // 评审数量达到要求后投票期从此时开始
+        let mut later = mock_env();
+        later.block.time = later.block.time.plus_seconds(3600);
+        execute(
+            deps.as_mut(),
+            later.clone(),
+            mock_info("reviewer2", &[]),
+            review(6),
+        )
+        .unwrap();
+        let proposal = status(&deps);
+        assert_eq!(ProposalStatus::Active, proposal.status);
+        assert_eq!(later.block.time.seconds() + 604800, proposal.voting_end);
+        assert!(assignments(&deps, "reviewer3").is_empty());
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetReviews { proposal_id: 1 },
+        )
+        .unwrap();
+        let reviews: ReviewsResponse = from_json(&res).unwrap();
+        assert_eq!(2, reviews.reviews.len());
+        assert_eq!(Addr::unchecked("reviewer1"), reviews.reviews[0].reviewer);
+        assert_eq!(8, reviews.reviews[0].score);
+
+        // 评审通过后提案者不能替换被评审的内容
+        let msg = ExecuteMsg::AmendProposal {
+            proposal_id: 1,
+            execution_data: crate::msg::ExecutionData::ArticlePublication {
+                ipfs_hash: "QmSwapped".to_string(),
+                doi: "10.1000/reviewed.article".to_string(),
+                metadata_uri: "https://example.com/reviewed.json".to_string(),
+            },
+        };
+        let err = execute(deps.as_mut(), later.clone(), mock_info("author", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::ReviewedProposalLocked {}));
+
+        execute(deps.as_mut(), later, mock_info("creator", &[]), vote(1)).unwrap();
+        assert_eq!(ProposalStatus::Executed, status(&deps).status);
+    }
+
+    #[test]
+    fn stalled_review_expires_and_voters_snapshot_at_voting() {
+        use crate::msg::{ProposalStatus, ReviewConfigMsg, ReviewRecommendation};
+
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: None,
+            proposal_deposit: Some(Coin::new(100, "inj")),
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: Some(ReviewConfigMsg {
+                editors: vec!["editor".to_string()],
+                required_reviews: 1,
+                review_period: 86400,
+                reveal_period: 0,
+            }),
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        let article = |doi: &str| ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmArticle".to_string(),
+            doi: doi.to_string(),
+            metadata_uri: "https://example.com/article.json".to_string(),
+            title: "Article".to_string(),
+            description: "Article for review".to_string(),
+            review_bounty: None,
+        };
+        let status = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, proposal_id: u64| {
+            let res = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GetProposal { proposal_id },
+            )
+            .unwrap();
+            let proposal: ProposalResponse = from_json(&res).unwrap();
+            proposal.proposal.status
+        };
+
+        // 没有编辑分配评审人的提案停留在评审阶段，截止时间前押金不能取回
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &coins(100, "inj")),
+            article("10.1000/stalled"),
+        )
+        .unwrap();
+        let claim = ExecuteMsg::ClaimDeposit { proposal_id: 1 };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &[]),
+            claim.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::VotingPeriodActive {}));
+        let finalize = ExecuteMsg::FinalizeProposal { proposal_id: 1 };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            finalize.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ReviewPeriodActive {}));
+
+        // 评审截止时间过后不能再分配评审人，提案过期，押金可以取回
+        let mut later = mock_env();
+        later.block.time = later.block.time.plus_seconds(86401);
+        let assign = |proposal_id: u64| ExecuteMsg::AssignReviewer {
+            proposal_id,
+            reviewer: "reviewer1".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            later.clone(),
+            mock_info("editor", &[]),
+            assign(1),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ReviewDeadlinePassed {}));
+        execute(
+            deps.as_mut(),
+            later.clone(),
+            mock_info("anyone", &[]),
+            finalize,
+        )
+        .unwrap();
+        assert_eq!(ProposalStatus::Expired, status(&deps, 1));
+        let res = execute(deps.as_mut(), later, mock_info("author", &[]), claim).unwrap();
+        assert_eq!(
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "author".to_string(),
+                amount: coins(100, "inj"),
+            }),
+            res.messages[0].msg
+        );
+
+        // 分配评审人后评审阶段延长到评审人的截止时间
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &coins(100, "inj")),
+            article("10.1000/reviewed"),
+        )
+        .unwrap();
+        let mut assigned_at = mock_env();
+        assigned_at.block.time = assigned_at.block.time.plus_seconds(3600);
+        execute(
+            deps.as_mut(),
+            assigned_at,
+            mock_info("editor", &[]),
+            assign(2),
+        )
+        .unwrap();
+
+        // 评审期间加入的成员在进入投票时记入快照
+        DAO_MEMBERS
+            .save(deps.as_mut().storage, "member1", &1)
+            .unwrap();
+        let mut reviewed_at = mock_env();
+        reviewed_at.block.time = reviewed_at.block.time.plus_seconds(86400 + 1800);
+        let msg = ExecuteMsg::SubmitReview {
+            proposal_id: 2,
+            score: 8,
+            recommendation: ReviewRecommendation::Accept,
+            report_ipfs_hash: "QmReport".to_string(),
+            salt: None,
+        };
+        execute(
+            deps.as_mut(),
+            reviewed_at.clone(),
+            mock_info("reviewer1", &[]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(ProposalStatus::Active, status(&deps, 2));
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetVoteCount { proposal_id: 2 },
+        )
+        .unwrap();
+        let count: VoteCountResponse = from_json(&res).unwrap();
+        assert_eq!(2, count.vote_count.total_eligible);
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 2,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), reviewed_at, mock_info("member1", &[]), msg).unwrap();
+    }
+
+    #[test]
+    fn blind_reviews_and_commit_reveal_votes() {
+        use crate::msg::{ProposalStatus, ReviewConfigMsg, ReviewRecommendation, ReviewsResponse};
+        use sha2::{Digest, Sha256};
+
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let at = |seconds: u64| {
+            let mut env = mock_env();
+            env.block.time = env.block.time.plus_seconds(seconds);
+            env
+        };
+        let commitment = |preimage: String| hex::encode(Sha256::digest(preimage.as_bytes()));
+
+        // 开启一小时揭示期的盲审，以及十分钟揭示期的提交-揭示投票
+        let msg = ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: None,
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: Some(ReviewConfigMsg {
+                editors: vec!["editor".to_string()],
+                required_reviews: 2,
+                review_period: 86400,
+                reveal_period: 3600,
+            }),
+            vote_reveal_period: Some(600),
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmBlind".to_string(),
+            doi: "10.1000/blind.article".to_string(),
+            metadata_uri: "https://example.com/blind.json".to_string(),
+            title: "Blind Reviewed Article".to_string(),
+            description: "Article reviewed without seeing other reports".to_string(),
+            review_bounty: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap();
+        let proposal = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| {
+            let res = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GetProposal { proposal_id: 1 },
+            )
+            .unwrap();
+            let proposal: ProposalResponse = from_json(&res).unwrap();
+            proposal.proposal
+        };
+        assert_eq!(600, proposal(&deps).vote_reveal_period);
+        for reviewer in ["reviewer1", "reviewer2", "reviewer3"] {
+            let msg = ExecuteMsg::AssignReviewer {
+                proposal_id: 1,
+                reviewer: reviewer.to_string(),
+            };
+            execute(deps.as_mut(), mock_env(), mock_info("editor", &[]), msg).unwrap();
+        }
+
+        // 盲审时不能直接公开评审
+        let review = |score: u8, salt: &str| ExecuteMsg::SubmitReview {
+            proposal_id: 1,
+            score,
+            recommendation: ReviewRecommendation::Accept,
+            report_ipfs_hash: format!("QmReport{}", score),
+            salt: Some(salt.to_string()),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("reviewer1", &[]),
+            review(8, "pepper"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::CommitmentRequired {}));
+
+        let commit_review = |reviewer: &str, score: u8, salt: &str| ExecuteMsg::CommitReview {
+            proposal_id: 1,
+            commitment: commitment(format!(
+                "1:{}:{}:accept:QmReport{}:{}",
+                reviewer, score, score, salt
+            )),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("reviewer1", &[]),
+            ExecuteMsg::CommitReview {
+                proposal_id: 1,
+                commitment: "not-a-hash".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidCommitment {}));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("reviewer1", &[]),
+            commit_review("reviewer1", 8, "pepper"),
+        )
+        .unwrap();
+        assert_eq!(ProposalStatus::UnderReview, proposal(&deps).status);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("reviewer2", &[]),
+            commit_review("reviewer2", 3, "salt"),
+        )
+        .unwrap();
+        let state = proposal(&deps);
+        assert_eq!(ProposalStatus::ReviewReveal, state.status);
+        assert_eq!(Some(at(3600).block.time.seconds()), state.reveal_end);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("reviewer3", &[]),
+            commit_review("reviewer3", 7, "salt"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ProposalNotUnderReview {}));
+
+        // 揭示内容必须与承诺一致
+        let err = execute(
+            deps.as_mut(),
+            at(100),
+            mock_info("reviewer1", &[]),
+            review(9, "pepper"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::CommitmentMismatch {}));
+        execute(
+            deps.as_mut(),
+            at(100),
+            mock_info("reviewer1", &[]),
+            review(8, "pepper"),
+        )
+        .unwrap();
+
+        // 揭示期结束后未揭示的评审人记为缺席，提案回到评审阶段
+        let finalize = ExecuteMsg::FinalizeProposal { proposal_id: 1 };
+        let err = execute(
+            deps.as_mut(),
+            at(200),
+            mock_info("editor", &[]),
+            finalize.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::RevealPeriodActive {}));
+        execute(
+            deps.as_mut(),
+            at(3601),
+            mock_info("editor", &[]),
+            finalize.clone(),
+        )
+        .unwrap();
+        let state = proposal(&deps);
+        assert_eq!(ProposalStatus::UnderReview, state.status);
+        assert_eq!(None, state.reveal_end);
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetReviews { proposal_id: 1 },
+        )
+        .unwrap();
+        let reviews: ReviewsResponse = from_json(&res).unwrap();
+        assert_eq!(1, reviews.reviews.len());
+        assert_eq!(vec![Addr::unchecked("reviewer2")], reviews.no_shows);
+        let msg = ExecuteMsg::AssignReviewer {
+            proposal_id: 1,
+            reviewer: "reviewer2".to_string(),
+        };
+        let err = execute(deps.as_mut(), at(3601), mock_info("editor", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::ReviewerAlreadyAssigned {}));
+
+        // 评审期间加入的成员在进入投票时记入快照
+        DAO_MEMBERS
+            .save(deps.as_mut().storage, "member1", &1)
+            .unwrap();
+
+        // 所有承诺揭示后立即进入投票
+        execute(
+            deps.as_mut(),
+            at(3700),
+            mock_info("reviewer3", &[]),
+            commit_review("reviewer3", 7, "salt"),
+        )
+        .unwrap();
+        assert_eq!(ProposalStatus::ReviewReveal, proposal(&deps).status);
+        execute(
+            deps.as_mut(),
+            at(3800),
+            mock_info("reviewer3", &[]),
+            review(7, "salt"),
+        )
+        .unwrap();
+        let state = proposal(&deps);
+        assert_eq!(ProposalStatus::Active, state.status);
+        let voting_end = state.voting_end;
+        assert_eq!(at(3800).block.time.seconds() + 604800, voting_end);
+
+        // 提交-揭示投票只接受承诺，揭示前不计票
+        let vote = ExecuteMsg::VoteOnProposal {
+            proposal_id: 1,
+            choice: VoteChoice::Yes,
+        };
+        let err = execute(deps.as_mut(), at(3800), mock_info("creator", &[]), vote).unwrap_err();
+        assert!(matches!(err, ContractError::CommitmentRequired {}));
+        let msg = ExecuteMsg::CommitVote {
+            proposal_id: 1,
+            commitment: commitment("1:creator:yes:s3cret".to_string()),
+        };
+        execute(deps.as_mut(), at(3800), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::CommitVote {
+            proposal_id: 1,
+            commitment: commitment("1:member1:no:s3cret".to_string()),
+        };
+        execute(deps.as_mut(), at(3800), mock_info("member1", &[]), msg).unwrap();
+        let msg = ExecuteMsg::CommitVote {
+            proposal_id: 1,
+            commitment: commitment("1:author:yes:s3cret".to_string()),
+        };
+        let err = execute(deps.as_mut(), at(3800), mock_info("author", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::NotDaoMember {}));
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetVoteCount { proposal_id: 1 },
+        )
+        .unwrap();
+        let count: crate::msg::VoteCountResponse = from_json(&res).unwrap();
+        assert_eq!(0, count.vote_count.yes);
+
+        let reveal = |choice: VoteChoice| ExecuteMsg::RevealVote {
+            proposal_id: 1,
+            choice,
+            salt: "s3cret".to_string(),
+        };
+        let reveal_start = voting_end - mock_env().block.time.seconds() + 1;
+        execute(
+            deps.as_mut(),
+            at(3900),
+            mock_info("creator", &[]),
+            reveal(VoteChoice::Yes),
+        )
+        .unwrap_err();
+        let err = execute(
+            deps.as_mut(),
+            at(reveal_start),
+            mock_info("creator", &[]),
+            reveal(VoteChoice::No),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::CommitmentMismatch {}));
+        let err = execute(
+            deps.as_mut(),
+            at(reveal_start),
+            mock_info("author", &[]),
+            reveal(VoteChoice::Yes),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NoCommitment {}));
+        execute(
+            deps.as_mut(),
+            at(reveal_start),
+            mock_info("creator", &[]),
+            reveal(VoteChoice::Yes),
+        )
+        .unwrap();
+        let state = proposal(&deps);
+        assert_eq!(ProposalStatus::VoteReveal, state.status);
+        assert_eq!(Some(voting_end + 600), state.reveal_end);
+
+        // 揭示期结束后才确定结果
+        let err = execute(
+            deps.as_mut(),
+            at(reveal_start),
+            mock_info("editor", &[]),
+            finalize.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::RevealPeriodActive {}));
+        let err = execute(
+            deps.as_mut(),
+            at(reveal_start + 600),
+            mock_info("creator", &[]),
+            reveal(VoteChoice::Yes),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::RevealPeriodEnded {}));
+        execute(
+            deps.as_mut(),
+            at(reveal_start + 600),
+            mock_info("editor", &[]),
+            finalize,
+        )
+        .unwrap();
+        assert_eq!(ProposalStatus::Executed, proposal(&deps).status);
+
+        // 提交承诺但未揭示的成员记为缺席
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetVoteNoShows { proposal_id: 1 },
+        )
+        .unwrap();
+        let no_shows: crate::msg::VoteNoShowsResponse = from_json(&res).unwrap();
+        assert_eq!(vec![Addr::unchecked("member1")], no_shows.no_shows);
+    }
+
+    #[test]
+    fn review_bounty_locked_after_failed_reveal_round() {
+        use crate::msg::{ReviewBountyStatus, ReviewConfigMsg, ReviewRecommendation};
+        use sha2::{Digest, Sha256};
+
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let at = |seconds: u64| {
+            let mut env = mock_env();
+            env.block.time = env.block.time.plus_seconds(seconds);
+            env
+        };
+        let commitment = |preimage: String| hex::encode(Sha256::digest(preimage.as_bytes()));
+
+        let msg = ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: None,
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: Some(ReviewConfigMsg {
+                editors: vec!["editor".to_string()],
+                required_reviews: 2,
+                review_period: 86400,
+                reveal_period: 3600,
+            }),
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmBounty".to_string(),
+            doi: "10.1000/bounty.reveal".to_string(),
+            metadata_uri: "https://example.com/bounty.json".to_string(),
+            title: "Article with bounty".to_string(),
+            description: "Blind review runs past the assignment deadline".to_string(),
+            review_bounty: Some(Coin::new(1000, "inj")),
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &coins(1000, "inj")),
+            msg,
+        )
+        .unwrap();
+        for reviewer in ["reviewer1", "reviewer2"] {
+            let msg = ExecuteMsg::AssignReviewer {
+                proposal_id: 1,
+                reviewer: reviewer.to_string(),
+            };
+            execute(deps.as_mut(), mock_env(), mock_info("editor", &[]), msg).unwrap();
+        }
+
+        // 临近评审截止时提交承诺，揭示期越过了分配时的截止时间
+        for reviewer in ["reviewer1", "reviewer2"] {
+            let msg = ExecuteMsg::CommitReview {
+                proposal_id: 1,
+                commitment: commitment(format!("1:{}:7:accept:QmReport:salt", reviewer)),
+            };
+            execute(deps.as_mut(), at(86000), mock_info(reviewer, &[]), msg).unwrap();
+        }
+        let msg = ExecuteMsg::SubmitReview {
+            proposal_id: 1,
+            score: 7,
+            recommendation: ReviewRecommendation::Accept,
+            report_ipfs_hash: "QmReport".to_string(),
+            salt: Some("salt".to_string()),
+        };
+        execute(deps.as_mut(), at(86100), mock_info("reviewer1", &[]), msg).unwrap();
+
+        // 评审不足时回到评审阶段，赏金截止时间随新的评审期限延长
+        let msg = ExecuteMsg::FinalizeProposal { proposal_id: 1 };
+        execute(deps.as_mut(), at(89601), mock_info("editor", &[]), msg).unwrap();
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetProposal { proposal_id: 1 },
+        )
+        .unwrap();
+        let proposal: ProposalResponse = from_json(&res).unwrap();
+        let bounty = proposal.proposal.review_bounty.unwrap();
+        assert_eq!(proposal.proposal.review_deadline, Some(bounty.deadline));
+        assert_eq!(at(89601 + 86400).block.time.seconds(), bounty.deadline);
+
+        let settle = ExecuteMsg::SettleReviewBounty { proposal_id: 1 };
+        let err = execute(
+            deps.as_mut(),
+            at(89601),
+            mock_info("author", &[]),
+            settle.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ReviewBountyLocked {}));
+
+        // 评审仍可继续被接受
+        let msg = ExecuteMsg::AcceptReview {
+            proposal_id: 1,
+            reviewer: "reviewer1".to_string(),
+        };
+        execute(deps.as_mut(), at(89601), mock_info("editor", &[]), msg).unwrap();
+
+        // 新的评审期限过后才能结算
+        execute(
+            deps.as_mut(),
+            at(89601 + 86401),
+            mock_info("author", &[]),
+            settle,
+        )
+        .unwrap();
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetProposal { proposal_id: 1 },
+        )
+        .unwrap();
+        let proposal: ProposalResponse = from_json(&res).unwrap();
+        assert_eq!(
+            ReviewBountyStatus::Settled,
+            proposal.proposal.review_bounty.unwrap().status
+        );
+    }
+
+    #[test]
+    fn review_bounty_paid_to_accepted_reviewers() {
+        use crate::msg::{
+            ReviewBountyStatus, ReviewConfigMsg, ReviewRecommendation, ReviewsResponse,
+        };
+        use cosmwasm_std::testing::MOCK_CONTRACT_ADDR;
+
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let at = |seconds: u64| {
+            let mut env = mock_env();
+            env.block.time = env.block.time.plus_seconds(seconds);
+            env
+        };
+        let article = |doi: &str, bounty: u128| ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmBounty".to_string(),
+            doi: doi.to_string(),
+            metadata_uri: "https://example.com/bounty.json".to_string(),
+            title: "Article with bounty".to_string(),
+            description: "Reviewers share the bounty".to_string(),
+            review_bounty: Some(Coin {
+                denom: "inj".to_string(),
+                amount: Uint128::new(bounty),
+            }),
+        };
+
+        // 未开启同行评审时不能附带赏金
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &coins(1000, "inj")),
+            article("10.1000/bounty.0", 1000),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ReviewBountyUnavailable {}));
+
+        let msg = ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: None,
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: Some(ReviewConfigMsg {
+                editors: vec!["editor".to_string()],
+                required_reviews: 2,
+                review_period: 86400,
+                reveal_period: 0,
+            }),
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let vote = |proposal_id: u64| ExecuteMsg::VoteOnProposal {
+            proposal_id,
+            choice: VoteChoice::Yes,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(0),
+        )
+        .unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+
+        // 附带的资金需要覆盖赏金
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &coins(500, "inj")),
+            article("10.1000/bounty.1", 1000),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InsufficientPayment {}));
+        // 扣除赏金后剩余的资金被拒绝
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &coins(1200, "inj")),
+            article("10.1000/bounty.1", 1000),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::UnexpectedFunds { .. }));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &coins(1000, "inj")),
+            article("10.1000/bounty.1", 1000),
+        )
+        .unwrap();
+        let bounty = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, proposal_id: u64| {
+            let res = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GetProposal { proposal_id },
+            )
+            .unwrap();
+            let proposal: ProposalResponse = from_json(&res).unwrap();
+            proposal.proposal.review_bounty.unwrap()
+        };
+        let escrowed = bounty(&deps, 1);
+        assert_eq!(coins(1000, "inj")[0], escrowed.amount);
+        assert_eq!(2, escrowed.reviewer_slots);
+        assert_eq!(ReviewBountyStatus::Escrowed, escrowed.status);
+
+        let assign = |proposal_id: u64, reviewer: &str| ExecuteMsg::AssignReviewer {
+            proposal_id,
+            reviewer: reviewer.to_string(),
+        };
+        let review = |proposal_id: u64| ExecuteMsg::SubmitReview {
+            proposal_id,
+            score: 7,
+            recommendation: ReviewRecommendation::Accept,
+            report_ipfs_hash: "QmReport".to_string(),
+            salt: None,
+        };
+        for reviewer in ["reviewer1", "reviewer2"] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("editor", &[]),
+                assign(1, reviewer),
+            )
+            .unwrap();
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(reviewer, &[]),
+                review(1),
+            )
+            .unwrap();
+        }
+
+        // 编辑或 DAO 接受评审
+        let accept = |proposal_id: u64, reviewer: &str| ExecuteMsg::AcceptReview {
+            proposal_id,
+            reviewer: reviewer.to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[]),
+            accept(1, "reviewer1"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotEditor {}));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("editor", &[]),
+            accept(1, "stranger"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ReviewNotFound {}));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("editor", &[]),
+            accept(1, "reviewer1"),
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("editor", &[]),
+            accept(1, "reviewer1"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ReviewAlreadyAccepted {}));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            accept(1, "reviewer2"),
+        )
+        .unwrap();
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetReviews { proposal_id: 1 },
+        )
+        .unwrap();
+        let reviews: ReviewsResponse = from_json(&res).unwrap();
+        assert!(reviews.reviews.iter().all(|review| review.accepted));
+
+        // 投票结束前不能结算
+        let settle = |proposal_id: u64| ExecuteMsg::SettleReviewBounty { proposal_id };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            settle(1),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ReviewBountyLocked {}));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(1),
+        )
+        .unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            settle(1),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "reviewer1".to_string(),
+                    amount: coins(500, "inj"),
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "reviewer2".to_string(),
+                    amount: coins(500, "inj"),
+                }),
+            ],
+            res.messages.into_iter().map(|m| m.msg).collect::<Vec<_>>()
+        );
+        assert_eq!(ReviewBountyStatus::Settled, bounty(&deps, 1).status);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            settle(1),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ReviewBountySettled {}));
+
+        // 评审截止时间过后仍未完成评审，未领取的名额退还提案者
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &coins(1001, "inj")),
+            article("10.1000/bounty.2", 1001),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("editor", &[]),
+            assign(2, "reviewer1"),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("reviewer1", &[]),
+            review(2),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("editor", &[]),
+            accept(2, "reviewer1"),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            at(3600),
+            mock_info("editor", &[]),
+            assign(2, "reviewer3"),
+        )
+        .unwrap();
+        assert_eq!(
+            at(3600 + 86400).block.time.seconds(),
+            bounty(&deps, 2).deadline
+        );
+        let err = execute(
+            deps.as_mut(),
+            at(86401),
+            mock_info("anyone", &[]),
+            settle(2),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ReviewBountyLocked {}));
+        let res = execute(
+            deps.as_mut(),
+            at(3600 + 86401),
+            mock_info("anyone", &[]),
+            settle(2),
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "reviewer1".to_string(),
+                    amount: coins(500, "inj"),
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "author".to_string(),
+                    amount: coins(501, "inj"),
+                }),
+            ],
+            res.messages.into_iter().map(|m| m.msg).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn citation_graph_records_edges_both_ways() {
+        use crate::msg::{CitationCounts, CitationCountsResponse, CitationEdgesResponse};
+
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        for (i, author) in ["author", "author", "author", "other"].iter().enumerate() {
+            let msg = ExecuteMsg::CreatePaperItem {
+                ipfs_hash: format!("QmGraph{}", i),
+                doi: format!("10.1000/graph.{}", i),
+                metadata_uri: "https://example.com/graph.json".to_string(),
+            };
+            execute(deps.as_mut(), mock_env(), mock_info(author, &[]), msg).unwrap();
+        }
+
+        let cite = |cited: &str, citing: Option<&str>| ExecuteMsg::CitePaper {
+            paper_id: cited.to_string(),
+            citing_paper_id: citing.map(str::to_string),
+        };
+        let fee = coins(100_000, "inj");
+        for (cited, citing) in [("0", "1"), ("0", "2"), ("1", "2")] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("author", &fee),
+                cite(cited, Some(citing)),
+            )
+            .unwrap();
+        }
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other", &fee),
+            cite("0", Some("3")),
+        )
+        .unwrap();
+        // 没有指明引用方论文的引用只计入付费引用
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("reader", &fee),
+            cite("0", None),
+        )
+        .unwrap();
+
+        // 自引、重复的边、非所有者和不存在的引用方论文都被拒绝
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &fee),
+            cite("1", Some("1")),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::SelfCitation {}));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &fee),
+            cite("0", Some("1")),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateCitation { .. }));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other", &fee),
+            cite("3", Some("1")),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotAuthorized {}));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("author", &fee),
+            cite("0", Some("99")),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TokenNotFound {}));
+
+        let edges = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, msg: QueryMsg| {
+            let res = query(deps.as_ref(), mock_env(), msg).unwrap();
+            let edges: CitationEdgesResponse = from_json(&res).unwrap();
+            edges
+                .edges
+                .into_iter()
+                .map(|edge| (edge.citing_paper_id, edge.cited_paper_id))
+                .collect::<Vec<_>>()
+        };
+        let pair = |citing: &str, cited: &str| (citing.to_string(), cited.to_string());
+
+        // 被引列表按引用方论文分页
+        let cited_by = |start_after: Option<&str>| QueryMsg::GetCitedBy {
+            paper_id: "0".to_string(),
+            start_after: start_after.map(str::to_string),
+            limit: Some(2),
+        };
+        assert_eq!(
+            vec![pair("1", "0"), pair("2", "0")],
+            edges(&deps, cited_by(None))
+        );
+        assert_eq!(vec![pair("3", "0")], edges(&deps, cited_by(Some("2"))));
+
+        // 参考文献列表按被引论文分页
+        let references = |start_after: Option<&str>| QueryMsg::GetReferences {
+            paper_id: "2".to_string(),
+            start_after: start_after.map(str::to_string),
+            limit: None,
+        };
+        assert_eq!(
+            vec![pair("2", "0"), pair("2", "1")],
+            edges(&deps, references(None))
+        );
+        assert_eq!(vec![pair("2", "1")], edges(&deps, references(Some("0"))));
+
+        let counts = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, paper_id: &str| {
+            let res = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GetCitationCounts {
+                    paper_id: paper_id.to_string(),
+                },
+            )
+            .unwrap();
+            let counts: CitationCountsResponse = from_json(&res).unwrap();
+            counts
+        };
+        let paper = counts(&deps, "0");
+        assert_eq!(3, paper.counts.cited_by);
+        assert_eq!(0, paper.counts.references);
+        assert_eq!(4, paper.paid_citations);
+        let paper = counts(&deps, "2");
+        assert_eq!(0, paper.counts.cited_by);
+        assert_eq!(2, paper.counts.references);
+        assert_eq!(0, paper.paid_citations);
+
+        // 销毁引用方论文时删除它的参考文献边，被引论文的计数随之减少
+        let msg = ExecuteMsg::Burn {
+            token_id: "2".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap();
+        assert_eq!(
+            vec![pair("1", "0"), pair("3", "0")],
+            edges(&deps, cited_by(None))
+        );
+        assert_eq!(2, counts(&deps, "0").counts.cited_by);
+        assert_eq!(0, counts(&deps, "1").counts.cited_by);
+        assert_eq!(CitationCounts::default(), counts(&deps, "2").counts);
+
+        // 被引论文经 DAO 销毁后，引用它的论文不再保留指向它的边
+        let msg = ExecuteMsg::SubmitBurnProposal {
+            token_id: "0".to_string(),
+            title: "Burn paper".to_string(),
+            description: "Paper was retracted".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("author", &[]), msg).unwrap();
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        assert!(edges(&deps, cited_by(None)).is_empty());
+        let references = |paper_id: &str| QueryMsg::GetReferences {
+            paper_id: paper_id.to_string(),
+            start_after: None,
+            limit: None,
+        };
+        assert!(edges(&deps, references("1")).is_empty());
+        assert!(edges(&deps, references("3")).is_empty());
+        assert_eq!(0, counts(&deps, "1").counts.references);
+        assert_eq!(0, counts(&deps, "3").counts.references);
+        assert_eq!(CitationCounts::default(), counts(&deps, "0").counts);
+    }
+
+    #[test]
+    fn reputation_tracks_research_activity() {
+        use crate::msg::{
+            ReputationConfig, ReputationLeaderboardResponse, ReputationResponse, ReviewConfigMsg,
+            ReviewRecommendation, VotingWeightSource,
+        };
+
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let reputation =
+            |deps: &cosmwasm_std::OwnedDeps<_, _, _>, env: cosmwasm_std::Env, address: &str| {
+                let res = query(
+                    deps.as_ref(),
+                    env,
+                    QueryMsg::GetReputation {
+                        address: address.to_string(),
+                    },
+                )
+                .unwrap();
+                let reputation: ReputationResponse = from_json(&res).unwrap();
+                reputation.reputation
+            };
+
+        let vote = |proposal_id: u64| ExecuteMsg::VoteOnProposal {
+            proposal_id,
+            choice: VoteChoice::Yes,
+        };
+
+        // 经 DAO 批准发表的论文和被他人引用增加声誉，自引不计入
+        let msg = ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmReputation".to_string(),
+            doi: "10.1000/reputation".to_string(),
+            metadata_uri: "https://example.com/reputation.json".to_string(),
+            title: "Reputation Article".to_string(),
+            description: "Published through the DAO".to_string(),
+            review_bounty: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(0),
+        )
+        .unwrap();
+        let cite = ExecuteMsg::CitePaper {
+            paper_id: "0".to_string(),
+            citing_paper_id: None,
+        };
+        for citer in ["citer", "creator"] {
+            let info = mock_info(citer, &coins(100_000, "inj"));
+            execute(deps.as_mut(), mock_env(), info, cite.clone()).unwrap();
+        }
+        let record = reputation(&deps, mock_env(), "creator");
+        assert_eq!(1, record.papers_published);
+        assert_eq!(1, record.citations_received);
+        assert_eq!(1, record.proposals_passed);
+        assert_eq!(15, record.score);
+
+        // 计分规则和计票来源只能通过提案修改，通过的提案同样计入声誉
+        let config = |points_per_vote: u64| ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: None,
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: Some(ReviewConfigMsg {
+                editors: vec!["editor".to_string()],
+                required_reviews: 1,
+                review_period: 86400,
+                reveal_period: 0,
+            }),
+            vote_reveal_period: None,
+            reputation_config: Some(ReputationConfig {
+                paper_points: 10,
+                citation_points: 2,
+                review_points: 5,
+                proposal_points: 3,
+                half_life: 86400,
+                points_per_vote,
+            }),
+            voting_weight_source: Some(VotingWeightSource::Reputation),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            config(0),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidReputationConfig {}));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            config(5),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(1),
+        )
+        .unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 1 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let record = reputation(&deps, mock_env(), "creator");
+        assert_eq!(2, record.proposals_passed);
+        assert_eq!(18, record.score);
+
+        // 分数按半衰期衰减，计数不变
+        let mut later = mock_env();
+        later.block.time = later.block.time.plus_seconds(43200);
+        assert_eq!(14, reputation(&deps, later, "creator").score);
+        let mut later = mock_env();
+        later.block.time = later.block.time.plus_seconds(86400);
+        let record = reputation(&deps, later, "creator");
+        assert_eq!(9, record.score);
+        assert_eq!(1, record.papers_published);
+
+        // 按声誉计票时，快照权重为成员权重加上声誉折算的票数
+        DAO_MEMBERS
+            .save(deps.as_mut().storage, "member1", &1)
+            .unwrap();
+        let msg = ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmReputable".to_string(),
+            doi: "10.1000/reputable".to_string(),
+            metadata_uri: "https://example.com/reputable.json".to_string(),
+            title: "Reputable Article".to_string(),
+            description: "Voting power follows reputation".to_string(),
+            review_bounty: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        // 评审中的提案还未记录投票权快照
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetVoteCount { proposal_id: 2 },
+        )
+        .unwrap();
+        let count: VoteCountResponse = from_json(&res).unwrap();
+        assert_eq!(0, count.vote_count.total_eligible);
+
+        // 评审被编辑接受后才增加评审人的声誉
+        let msg = ExecuteMsg::AssignReviewer {
+            proposal_id: 2,
+            reviewer: "reviewer1".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("editor", &[]), msg).unwrap();
+        let msg = ExecuteMsg::SubmitReview {
+            proposal_id: 2,
+            score: 9,
+            recommendation: ReviewRecommendation::Accept,
+            report_ipfs_hash: "QmReport".to_string(),
+            salt: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("reviewer1", &[]), msg).unwrap();
+        // 评审完成进入投票时记录快照
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetVoteCount { proposal_id: 2 },
+        )
+        .unwrap();
+        let count: VoteCountResponse = from_json(&res).unwrap();
+        assert_eq!(5, count.vote_count.total_eligible);
+        assert_eq!(0, reputation(&deps, mock_env(), "reviewer1").score);
+        let msg = ExecuteMsg::AcceptReview {
+            proposal_id: 2,
+            reviewer: "reviewer1".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("editor", &[]), msg).unwrap();
+        let record = reputation(&deps, mock_env(), "reviewer1");
+        assert_eq!(1, record.reviews_completed);
+        assert_eq!(5, record.score);
+
+        // 创建者的 4 票超过通过阈值，文章发布后计入论文和通过的提案
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            vote(2),
+        )
+        .unwrap();
+        let record = reputation(&deps, mock_env(), "creator");
+        assert_eq!(2, record.papers_published);
+        assert_eq!(3, record.proposals_passed);
+        assert_eq!(31, record.score);
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetReputationLeaderboard { limit: None },
+        )
+        .unwrap();
+        let leaderboard: ReputationLeaderboardResponse = from_json(&res).unwrap();
+        let ranking: Vec<(String, u64)> = leaderboard
+            .entries
+            .into_iter()
+            .map(|entry| (entry.address.to_string(), entry.score))
+            .collect();
+        assert_eq!(
+            vec![("creator".to_string(), 31), ("reviewer1".to_string(), 5)],
+            ranking
+        );
+    }
+
+    #[test]
+    fn self_minted_papers_and_self_citations_earn_no_reputation() {
+        use crate::msg::{EffectivePowerResponse, ReputationResponse, VotingWeightSource};
+
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+        DAO_MEMBERS
+            .save(deps.as_mut().storage, "farmer", &1)
+            .unwrap();
+        DAO_CONFIG
+            .update(
+                deps.as_mut().storage,
+                |mut config| -> cosmwasm_std::StdResult<_> {
+                    config.reputation_config.points_per_vote = 1;
+                    config.voting_weight_source = VotingWeightSource::Reputation;
+                    Ok(config)
+                },
+            )
+            .unwrap();
+
+        // 成员直接铸造两篇论文，不经过 DAO 批准
+        for id in ["0", "1"] {
+            let msg = ExecuteMsg::CreatePaperItem {
+                ipfs_hash: format!("QmFarm{}", id),
+                doi: format!("10.1000/farm{}", id),
+                metadata_uri: "https://example.com/farm.json".to_string(),
+            };
+            execute(deps.as_mut(), mock_env(), mock_info("farmer", &[]), msg).unwrap();
+        }
+        let msg = ExecuteMsg::ApproveAll {
+            operator: "sock".to_string(),
+            expires: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("farmer", &[]), msg).unwrap();
+
+        // 自己的论文互相引用、经操作员引用、由他人引用未经批准的论文都不计分
+        let citations = [
+            ("farmer", "0", None),
+            ("farmer", "0", Some("1")),
+            ("sock", "1", Some("0")),
+            ("sock", "0", None),
+        ];
+        for (citer, paper_id, citing_paper_id) in citations {
+            let msg = ExecuteMsg::CitePaper {
+                paper_id: paper_id.to_string(),
+                citing_paper_id: citing_paper_id.map(str::to_string),
+            };
+            let info = mock_info(citer, &coins(100_000, "inj"));
+            execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        }
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetReputation {
+                address: "farmer".to_string(),
+            },
+        )
+        .unwrap();
+        let reputation: ReputationResponse = from_json(&res).unwrap();
+        assert_eq!(0, reputation.reputation.papers_published);
+        assert_eq!(0, reputation.reputation.citations_received);
+        assert_eq!(0, reputation.reputation.score);
+
+        // 投票权快照只有成员权重
+        let msg = ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmFarmed".to_string(),
+            doi: "10.1000/farmed".to_string(),
+            metadata_uri: "https://example.com/farmed.json".to_string(),
+            title: "Farmed".to_string(),
+            description: "Weight must not grow".to_string(),
+            review_bounty: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("farmer", &[]), msg).unwrap();
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetEffectivePower {
+                proposal_id: 0,
+                member: "farmer".to_string(),
+            },
+        )
+        .unwrap();
+        let power: EffectivePowerResponse = from_json(&res).unwrap();
+        assert_eq!(1, power.own_power);
+    }
+
+    #[test]
+    fn proposer_can_amend_and_withdraw_proposals() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            name: "Research Data NFT".to_string(),
+            symbol: "RDN".to_string(),
+            owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+        for member in ["member1", "member2"] {
+            DAO_MEMBERS.save(deps.as_mut().storage, member, &1).unwrap();
+        }
+
+        let msg = ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmTypo".to_string(),
+            doi: "10.1000/typo".to_string(),
+            metadata_uri: "https://example.com/article.json".to_string(),
+            title: "Article".to_string(),
+            description: "Article with a typo".to_string(),
+            review_bounty: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("member1", &[]), msg).unwrap();
 
-    // ===== 基础功能测试 =====
+        let amend = |execution_data: crate::msg::ExecutionData| ExecuteMsg::AmendProposal {
+            proposal_id: 0,
+            execution_data,
+        };
+        let fixed = crate::msg::ExecutionData::ArticlePublication {
+            ipfs_hash: "QmFixed".to_string(),
+            doi: "https://doi.org/10.1000/FIXED".to_string(),
+            metadata_uri: "https://example.com/article.json".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member1", &[]),
+            amend(fixed.clone()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            amend(crate::msg::ExecutionData::DenomsUpdate {
+                accepted_denoms: vec!["inj".to_string()],
+            }),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidAmendment {}));
+
+        // 已有投票时不能撤回
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            ExecuteMsg::WithdrawProposal { proposal_id: 0 },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ProposalHasVotes {}));
+
+        // 修改后投票被清空，投票截止时间不变
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(3600);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("creator", &[]),
+            amend(fixed),
+        )
+        .unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "cleared_votes" && attr.value == "1"));
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetProposal { proposal_id: 0 },
+        )
+        .unwrap();
+        let proposal: ProposalResponse = from_json(res).unwrap();
+        assert_eq!(
+            mock_env().block.time.seconds() + 604800,
+            proposal.proposal.voting_end
+        );
+        assert_eq!(
+            Some(crate::msg::ExecutionData::ArticlePublication {
+                ipfs_hash: "QmFixed".to_string(),
+                doi: "10.1000/fixed".to_string(),
+                metadata_uri: "https://example.com/article.json".to_string(),
+            }),
+            proposal.proposal.execution_data
+        );
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetVoteCount { proposal_id: 0 },
+        )
+        .unwrap();
+        let count: VoteCountResponse = from_json(res).unwrap();
+        assert_eq!(0, count.vote_count.yes);
+        assert_eq!(3, count.vote_count.total_eligible);
+
+        // 投票清空后可以撤回，撤回后不能再投票或撤回
+        let msg = ExecuteMsg::WithdrawProposal { proposal_id: 0 };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("creator", &[]),
+            msg.clone(),
+        )
+        .unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "proposal_status" && attr.value == "Withdrawn"));
+        let vote = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), env.clone(), mock_info("member1", &[]), vote).unwrap_err();
+        execute(deps.as_mut(), env, mock_info("creator", &[]), msg).unwrap_err();
+    }
 
     #[test]
-    fn proper_initialization() {
+    fn amending_does_not_escape_spam_slash() {
         let mut deps = mock_dependencies();
 
         let msg = InstantiateMsg {
             name: "Research Data NFT".to_string(),
             symbol: "RDN".to_string(),
             owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
         };
-        let info = mock_info("creator", &coins(1000, "earth"));
+        instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
 
-        let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
-        assert_eq!(0, res.messages.len());
+        let msg = ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: None,
+            proposal_deposit: Some(Coin::new(100, "inj")),
+            slash_spam_deposits: Some(true),
+            execution_delay: None,
+            veto_council: None,
+            review_config: None,
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice: VoteChoice::Yes,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let msg = ExecuteMsg::ExecuteProposal { proposal_id: 0 };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        for member in ["member1", "member2"] {
+            DAO_MEMBERS.save(deps.as_mut().storage, member, &1).unwrap();
+        }
 
-        let res = query(deps.as_ref(), mock_env(), QueryMsg::ContractInfo {}).unwrap();
-        let value: crate::msg::ContractInfoResponse = from_json(&res).unwrap();
-        assert_eq!("Research Data NFT", value.name);
-        assert_eq!("RDN", value.symbol);
+        let msg = ExecuteMsg::SubmitArticleProposal {
+            ipfs_hash: "QmSpam".to_string(),
+            doi: "10.1000/spam".to_string(),
+            metadata_uri: "https://example.com/article.json".to_string(),
+            title: "Spam".to_string(),
+            description: "Spam article".to_string(),
+            review_bounty: None,
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("spammer", &coins(100, "inj")),
+            msg,
+        )
+        .unwrap();
+        let msg = ExecuteMsg::VoteOnProposal {
+            proposal_id: 1,
+            choice: VoteChoice::NoWithVeto,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("member1", &[]), msg).unwrap();
+
+        // 修改清空否决票前先按现有票数没收押金
+        let msg = ExecuteMsg::AmendProposal {
+            proposal_id: 1,
+            execution_data: crate::msg::ExecutionData::ArticlePublication {
+                ipfs_hash: "QmStillSpam".to_string(),
+                doi: "10.1000/spam".to_string(),
+                metadata_uri: "https://example.com/article.json".to_string(),
+            },
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("spammer", &[]), msg).unwrap();
+        let msg = ExecuteMsg::WithdrawProposal { proposal_id: 1 };
+        execute(deps.as_mut(), mock_env(), mock_info("spammer", &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::ClaimDeposit { proposal_id: 1 };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("spammer", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::DepositSlashed {}));
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::TreasuryBalance {}).unwrap();
+        let treasury: TreasuryBalanceResponse = from_json(&res).unwrap();
+        assert_eq!(vec![Coin::new(100, "inj")], treasury.balances);
     }
 
     #[test]
-    fn dao_initialization() {
+    fn voter_eligibility_is_snapshotted_at_creation() {
         let mut deps = mock_dependencies();
 
         let msg = InstantiateMsg {
             name: "Research Data NFT".to_string(),
             symbol: "RDN".to_string(),
             owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
         };
-        let info = mock_info("creator", &coins(1000, "earth"));
-
-        let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
-
-        // Verify DAO initialization
-        assert!(res
-            .attributes
-            .iter()
-            .any(|attr| attr.key == "dao_initialized" && attr.value == "true"));
-        assert!(res
-            .attributes
-            .iter()
-            .any(|attr| attr.key == "first_dao_member" && attr.value == "creator"));
-
-        // Verify creator is DAO member
-        let is_member = DAO_MEMBERS.load(deps.as_ref().storage, "creator").unwrap();
-        assert_eq!(true, is_member);
-
-        // Verify DAO config defaults
-        let dao_config = DAO_CONFIG.load(deps.as_ref().storage).unwrap();
-        assert_eq!(604800, dao_config.voting_period); // 7 days
-        assert_eq!(51, dao_config.approval_threshold); // 51%
-        assert_eq!(1, dao_config.min_members);
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+        DAO_MEMBERS
+            .save(deps.as_mut().storage, "member1", &1)
+            .unwrap();
 
-        let proposal_counter = PROPOSAL_COUNTER.load(deps.as_ref().storage).unwrap();
-        assert_eq!(0, proposal_counter);
-    }
+        let msg = ExecuteMsg::UpdateDaoConfig {
+            voting_period: None,
+            approval_threshold: None,
+            min_members: None,
+            quorum: Some(50),
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: None,
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
 
-    // ===== NFT基础功能测试 =====
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetProposal { proposal_id: 0 },
+        )
+        .unwrap();
+        let proposal: ProposalResponse = from_json(res).unwrap();
+        assert_eq!(mock_env().block.height, proposal.proposal.snapshot_height);
 
-    // #[test]
-    // fn create_data_item() {
-    //     let mut deps = mock_dependencies();
+        // 提案创建后的成员变动不影响投票资格
+        DAO_MEMBERS
+            .save(deps.as_mut().storage, "member2", &1)
+            .unwrap();
+        DAO_MEMBERS.remove(deps.as_mut().storage, "member1");
 
-    //     let msg = InstantiateMsg {
-    //         name: "Research Data NFT".to_string(),
-    //         symbol: "RDN".to_string(),
-    //         owner: "creator".to_string(),
-    //     };
-    //     let info = mock_info("creator", &coins(2, "token"));
-    //     let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+        let vote = |choice: VoteChoice| ExecuteMsg::VoteOnProposal {
+            proposal_id: 0,
+            choice,
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member2", &[]),
+            vote(VoteChoice::Yes),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotDaoMember {}));
 
-    //     let info = mock_info("creator", &coins(2, "token"));
-    //     let msg = ExecuteMsg::CreateDataItem {
-    //         ipfs_hash: "QmTest".to_string(),
-    //         price: Uint128::new(1000),
-    //         is_public: false,
-    //         metadata_uri: "https://example.com/metadata.json".to_string(),
-    //     };
-    //     let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
-    //     assert_eq!(0, res.messages.len());
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member1", &[]),
+            vote(VoteChoice::No),
+        )
+        .unwrap();
 
-    //     let res = query(
-    //         deps.as_ref(),
-    //         mock_env(),
-    //         QueryMsg::GetDataItem {
-    //             token_id: "0".to_string(),
-    //         },
-    //     )
-    //     .unwrap();
-    //     let data_item: crate::msg::DataItem = from_json(&res).unwrap();
-    //     assert_eq!("QmTest", data_item.ipfs_hash);
-    //     assert_eq!(Uint128::new(1000), data_item.price);
-    //     assert_eq!(false, data_item.is_public);
-    // }
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetVoteCount { proposal_id: 0 },
+        )
+        .unwrap();
+        let count: VoteCountResponse = from_json(res).unwrap();
+        assert_eq!(1, count.vote_count.no);
+        assert_eq!(2, count.vote_count.total_eligible);
+    }
 
     // #[test]
     // fn create_paper_item() {
@@ -114,6 +4089,7 @@ mod tests {
     //         name: "Research Data NFT".to_string(),
     //         symbol: "RDN".to_string(),
     //         owner: "creator".to_string(),
+    //         accepted_denoms: vec!["inj".to_string()],
     //     };
     //     let info = mock_info("creator", &coins(2, "token"));
     //     let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
@@ -147,6 +4123,7 @@ mod tests {
     //         name: "Research Data NFT".to_string(),
     //         symbol: "RDN".to_string(),
     //         owner: "creator".to_string(),
+    //         accepted_denoms: vec!["inj".to_string()],
     //     };
     //     let info = mock_info("creator", &coins(2, "token"));
     //     let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
@@ -189,18 +4166,20 @@ mod tests {
             name: "Research Data NFT".to_string(),
             symbol: "RDN".to_string(),
             owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
         };
         let info = mock_info("creator", &coins(2, "token"));
         let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         // Submit article proposal
-        let info = mock_info("author", &coins(2, "token"));
+        let info = mock_info("author", &[]);
         let msg = ExecuteMsg::SubmitArticleProposal {
             ipfs_hash: "QmTestArticle123".to_string(),
             doi: "10.1000/test.article.2024".to_string(),
             metadata_uri: "https://example.com/article.json".to_string(),
             title: "Test Article".to_string(),
             description: "A test article for DAO approval".to_string(),
+            review_bounty: None,
         };
         let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
@@ -214,7 +4193,7 @@ mod tests {
             .any(|attr| attr.key == "proposal_id" && attr.value == "0"));
 
         // Vote on proposal
-        let info = mock_info("creator", &coins(2, "token"));
+        let info = mock_info("creator", &[]);
         let msg = ExecuteMsg::VoteOnProposal {
             proposal_id: 0,
             choice: VoteChoice::Yes,
@@ -256,16 +4235,26 @@ mod tests {
             name: "Research Data NFT".to_string(),
             symbol: "RDN".to_string(),
             owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
         };
         let info = mock_info("creator", &coins(2, "token"));
         let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         // Submit config update proposal
-        let info = mock_info("creator", &coins(2, "token"));
+        let info = mock_info("creator", &[]);
         let msg = ExecuteMsg::UpdateDaoConfig {
             voting_period: Some(1209600), // 14 days
             approval_threshold: Some(60), // 60%
             min_members: Some(1),
+            quorum: None,
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: None,
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
         };
         let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
@@ -287,15 +4276,17 @@ mod tests {
             name: "Research Data NFT".to_string(),
             symbol: "RDN".to_string(),
             owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
         };
         let info = mock_info("creator", &coins(2, "token"));
         let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         // Submit member addition proposal
-        let info = mock_info("creator", &coins(2, "token"));
+        let info = mock_info("creator", &[]);
         let msg = ExecuteMsg::SubmitMemberProposal {
             member_address: "new_member".to_string(),
             action: crate::msg::MemberAction::Add,
+            weight: None,
             title: "Add New Member".to_string(),
             description: "Adding a new member to the DAO".to_string(),
         };
@@ -325,23 +4316,25 @@ mod tests {
             name: "Research Data NFT".to_string(),
             symbol: "RDN".to_string(),
             owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
         };
         let info = mock_info("creator", &coins(2, "token"));
         let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         // Submit article proposal
-        let info = mock_info("creator", &coins(2, "token"));
+        let info = mock_info("creator", &[]);
         let msg = ExecuteMsg::SubmitArticleProposal {
             ipfs_hash: "QmNonMemberTest123".to_string(),
             doi: "10.1000/non.member.2024".to_string(),
             metadata_uri: "https://example.com/nonmember.json".to_string(),
             title: "Non-member Test".to_string(),
             description: "Testing non-member voting restriction".to_string(),
+            review_bounty: None,
         };
         let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         // Try to vote as non-DAO member
-        let info = mock_info("non_member", &coins(2, "token"));
+        let info = mock_info("non_member", &[]);
         let msg = ExecuteMsg::VoteOnProposal {
             proposal_id: 0,
             choice: VoteChoice::Yes,
@@ -363,26 +4356,45 @@ mod tests {
             name: "Research Data NFT".to_string(),
             symbol: "RDN".to_string(),
             owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
         };
         let info = mock_info("creator", &coins(2, "token"));
         let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         // Test invalid approval threshold (0%)
-        let info = mock_info("creator", &coins(2, "token"));
+        let info = mock_info("creator", &[]);
         let msg = ExecuteMsg::UpdateDaoConfig {
             voting_period: None,
             approval_threshold: Some(0),
             min_members: None,
+            quorum: None,
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: None,
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
         };
         let res = execute(deps.as_mut(), mock_env(), info, msg);
         assert!(res.is_err());
 
         // Test invalid min_members (0)
-        let info = mock_info("creator", &coins(2, "token"));
+        let info = mock_info("creator", &[]);
         let msg = ExecuteMsg::UpdateDaoConfig {
             voting_period: None,
             approval_threshold: None,
             min_members: Some(0),
+            quorum: None,
+            proposal_deposit: None,
+            slash_spam_deposits: None,
+            execution_delay: None,
+            veto_council: None,
+            review_config: None,
+            vote_reveal_period: None,
+            reputation_config: None,
+            voting_weight_source: None,
         };
         let res = execute(deps.as_mut(), mock_env(), info, msg);
         assert!(res.is_err());
@@ -396,15 +4408,17 @@ mod tests {
             name: "Research Data NFT".to_string(),
             symbol: "RDN".to_string(),
             owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
         };
         let info = mock_info("creator", &coins(2, "token"));
         let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         // Try to remove the only member (creator)
-        let info = mock_info("creator", &coins(2, "token"));
+        let info = mock_info("creator", &[]);
         let msg = ExecuteMsg::SubmitMemberProposal {
             member_address: "creator".to_string(),
             action: crate::msg::MemberAction::Remove,
+            weight: None,
             title: "Remove Last Member".to_string(),
             description: "Attempting to remove the last member".to_string(),
         };
@@ -427,18 +4441,20 @@ mod tests {
             name: "Research Data NFT".to_string(),
             symbol: "RDN".to_string(),
             owner: "creator".to_string(),
+            accepted_denoms: vec!["inj".to_string()],
         };
         let info = mock_info("creator", &coins(2, "token"));
         let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         // 1. Submit article proposal
-        let info = mock_info("author", &coins(2, "token"));
+        let info = mock_info("author", &[]);
         let msg = ExecuteMsg::SubmitArticleProposal {
             ipfs_hash: "QmWorkflowTest123".to_string(),
             doi: "10.1000/workflow.test.2024".to_string(),
             metadata_uri: "https://example.com/workflow.json".to_string(),
             title: "Complete Workflow Test".to_string(),
             description: "Testing complete DAO governance workflow".to_string(),
+            review_bounty: None,
         };
         let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
         assert!(res
@@ -447,7 +4463,7 @@ mod tests {
             .any(|attr| attr.key == "proposal_id" && attr.value == "0"));
 
         // 2. Vote on proposal
-        let info = mock_info("creator", &coins(2, "token"));
+        let info = mock_info("creator", &[]);
         let msg = ExecuteMsg::VoteOnProposal {
             proposal_id: 0,
             choice: VoteChoice::Yes,
@@ -477,9 +4493,14 @@ mod tests {
         let info = mock_info("citer", &coins(100_000, "inj"));
         let msg = ExecuteMsg::CitePaper {
             paper_id: "0".to_string(),
+            citing_paper_id: None,
         };
         let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
-        assert_eq!(2, res.messages.len()); // Payment to author and DAO
+        assert_eq!(1, res.messages.len()); // Payment to author, DAO share stays in treasury
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::TreasuryBalance {}).unwrap();
+        let treasury: TreasuryBalanceResponse = from_json(&res).unwrap();
+        assert_eq!(vec![Coin::new(5_000, "inj")], treasury.balances);
 
         let res = query(
             deps.as_ref(),
@@ -493,3 +4514,491 @@ mod tests {
         assert_eq!(1, citations.len());
     }
 }
+
+#[cfg(test)]
+mod multitest {
+    use crate::contracts::{
+        execute::execute, instantiate::instantiate, query::query, reply::reply,
+    };
+    use crate::error::ContractError;
+    use crate::msg::{
+        ExecuteMsg, InstantiateMsg, MemberAction, OwnerOfResponse, ProposalResponse,
+        ProposalStatus, QueryMsg, VoteChoice,
+    };
+    use cosmwasm_std::{coins, to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Uint128, WasmMsg};
+    use cw_multi_test::{App, ContractWrapper, Executor};
+
+    /// 测试用的 NFT 接收合约：记录收到的 token，消息为 "reject" 时拒绝接收
+    mod receiver {
+        use cosmwasm_std::{
+            from_json, to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+            StdError, StdResult,
+        };
+        use cw721::Cw721ReceiveMsg;
+        use cw_storage_plus::Item;
+        use schemars::JsonSchema;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+        #[serde(rename_all = "snake_case")]
+        pub enum ExecuteMsg {
+            ReceiveNft(Cw721ReceiveMsg),
+        }
+
+        #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+        #[serde(rename_all = "snake_case")]
+        pub enum QueryMsg {
+            Received {},
+        }
+
+        const RECEIVED: Item<Vec<String>> = Item::new("received");
+
+        pub fn instantiate(
+            deps: DepsMut,
+            _env: Env,
+            _info: MessageInfo,
+            _msg: Empty,
+        ) -> StdResult<Response> {
+            RECEIVED.save(deps.storage, &vec![])?;
+            Ok(Response::new())
+        }
+
+        pub fn execute(
+            deps: DepsMut,
+            _env: Env,
+            _info: MessageInfo,
+            msg: ExecuteMsg,
+        ) -> StdResult<Response> {
+            let ExecuteMsg::ReceiveNft(receive) = msg;
+            let action: String = from_json(&receive.msg)?;
+            if action == "reject" {
+                return Err(StdError::generic_err("rejected"));
+            }
+
+            let mut received = RECEIVED.load(deps.storage)?;
+            received.push(format!("{}:{}", receive.sender, receive.token_id));
+            RECEIVED.save(deps.storage, &received)?;
+            Ok(Response::new())
+        }
+
+        pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+            match msg {
+                QueryMsg::Received {} => to_json_binary(&RECEIVED.load(deps.storage)?),
+            }
+        }
+    }
+
+    fn setup() -> (App, Addr, Addr) {
+        let mut app = App::default();
+
+        let nft_code = app.store_code(Box::new(
+            ContractWrapper::new(execute, instantiate, query).with_reply(reply),
+        ));
+        let receiver_code = app.store_code(Box::new(ContractWrapper::new(
+            receiver::execute,
+            receiver::instantiate,
+            receiver::query,
+        )));
+
+        let nft = app
+            .instantiate_contract(
+                nft_code,
+                Addr::unchecked("creator"),
+                &InstantiateMsg {
+                    name: "Research Data NFT".to_string(),
+                    symbol: "RDN".to_string(),
+                    owner: "creator".to_string(),
+                    accepted_denoms: vec!["inj".to_string()],
+                },
+                &[],
+                "nft",
+                None,
+            )
+            .unwrap();
+        let receiver = app
+            .instantiate_contract(
+                receiver_code,
+                Addr::unchecked("creator"),
+                &cosmwasm_std::Empty {},
+                &[],
+                "receiver",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("author"),
+            nft.clone(),
+            &ExecuteMsg::CreateDataItem {
+                ipfs_hash: "QmDataset".to_string(),
+                metadata_uri: "https://example.com/dataset.json".to_string(),
+                price: Uint128::zero(),
+                denom: None,
+                is_public: true,
+            },
+            &[],
+        )
+        .unwrap();
+
+        (app, nft, receiver)
+    }
+
+    fn owner_of(app: &App, nft: &Addr, token_id: &str) -> String {
+        let res: OwnerOfResponse = app
+            .wrap()
+            .query_wasm_smart(
+                nft,
+                &QueryMsg::OwnerOf {
+                    token_id: token_id.to_string(),
+                    include_expired: None,
+                },
+            )
+            .unwrap();
+        res.owner
+    }
+
+    /// 由唯一的 DAO 成员 creator 提交、通过并执行自定义提案
+    fn run_custom_proposal(app: &mut App, nft: &Addr, msgs: Vec<CosmosMsg>) -> u64 {
+        let res = app
+            .execute_contract(
+                Addr::unchecked("creator"),
+                nft.clone(),
+                &ExecuteMsg::SubmitCustomProposal {
+                    title: "Custom".to_string(),
+                    description: "Custom messages".to_string(),
+                    msgs,
+                },
+                &[],
+            )
+            .unwrap();
+        let proposal_id: u64 = res
+            .events
+            .iter()
+            .flat_map(|event| event.attributes.iter())
+            .find(|attr| attr.key == "proposal_id")
+            .unwrap()
+            .value
+            .parse()
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("creator"),
+            nft.clone(),
+            &ExecuteMsg::VoteOnProposal {
+                proposal_id,
+                choice: VoteChoice::Yes,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            Addr::unchecked("creator"),
+            nft.clone(),
+            &ExecuteMsg::ExecuteProposal { proposal_id },
+            &[],
+        )
+        .unwrap();
+        proposal_id
+    }
+
+    fn proposal_status(app: &App, nft: &Addr, proposal_id: u64) -> ProposalStatus {
+        let res: ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(nft, &QueryMsg::GetProposal { proposal_id })
+            .unwrap();
+        res.proposal.status
+    }
+
+    fn receive_msg(receiver: &Addr, action: &str) -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: receiver.to_string(),
+            msg: to_json_binary(&receiver::ExecuteMsg::ReceiveNft(cw721::Cw721ReceiveMsg {
+                sender: "dao".to_string(),
+                token_id: "grant".to_string(),
+                msg: to_json_binary(action).unwrap(),
+            }))
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()
+    }
+
+    #[test]
+    fn custom_proposal_calls_contract() {
+        let (mut app, nft, receiver) = setup();
+
+        let proposal_id =
+            run_custom_proposal(&mut app, &nft, vec![receive_msg(&receiver, "accept")]);
+
+        assert_eq!(
+            ProposalStatus::Executed,
+            proposal_status(&app, &nft, proposal_id)
+        );
+        let received: Vec<String> = app
+            .wrap()
+            .query_wasm_smart(&receiver, &receiver::QueryMsg::Received {})
+            .unwrap();
+        assert_eq!(vec!["dao:grant".to_string()], received);
+    }
+
+    #[test]
+    fn custom_proposal_cannot_spend_escrowed_deposit() {
+        let (mut app, nft, receiver) = setup();
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("creator"), coins(1000, "inj"))
+                .unwrap()
+        });
+
+        // 通过配置提案开启押金
+        app.execute_contract(
+            Addr::unchecked("creator"),
+            nft.clone(),
+            &ExecuteMsg::UpdateDaoConfig {
+                voting_period: None,
+                approval_threshold: None,
+                min_members: None,
+                quorum: None,
+                proposal_deposit: Some(Coin::new(100, "inj")),
+                slash_spam_deposits: None,
+                execution_delay: None,
+                veto_council: None,
+                review_config: None,
+                vote_reveal_period: None,
+                reputation_config: None,
+                voting_weight_source: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            Addr::unchecked("creator"),
+            nft.clone(),
+            &ExecuteMsg::VoteOnProposal {
+                proposal_id: 0,
+                choice: VoteChoice::Yes,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            Addr::unchecked("creator"),
+            nft.clone(),
+            &ExecuteMsg::ExecuteProposal { proposal_id: 0 },
+            &[],
+        )
+        .unwrap();
+
+        // 提交一个带押金的提案，押金托管在合约中
+        app.execute_contract(
+            Addr::unchecked("creator"),
+            nft.clone(),
+            &ExecuteMsg::SubmitMemberProposal {
+                member_address: "member2".to_string(),
+                action: MemberAction::Add,
+                weight: None,
+                title: "Add member".to_string(),
+                description: "Add member2".to_string(),
+            },
+            &coins(100, "inj"),
+        )
+        .unwrap();
+        let escrow = app.wrap().query_balance(&nft, "inj").unwrap();
+        assert_eq!(Uint128::new(100), escrow.amount);
+
+        // 自定义提案不能通过银行转账或附带资金的合约调用动用托管的押金
+        let bank_send: CosmosMsg = BankMsg::Send {
+            to_address: "grantee".to_string(),
+            amount: coins(100, "inj"),
+        }
+        .into();
+        let funded_call: CosmosMsg = WasmMsg::Execute {
+            contract_addr: receiver.to_string(),
+            msg: to_json_binary(&receiver::ExecuteMsg::ReceiveNft(cw721::Cw721ReceiveMsg {
+                sender: "dao".to_string(),
+                token_id: "grant".to_string(),
+                msg: to_json_binary("accept").unwrap(),
+            }))
+            .unwrap(),
+            funds: coins(100, "inj"),
+        }
+        .into();
+        for msgs in [
+            vec![bank_send],
+            vec![receive_msg(&receiver, "accept"), funded_call],
+        ] {
+            let err = app
+                .execute_contract(
+                    Addr::unchecked("creator"),
+                    nft.clone(),
+                    &ExecuteMsg::SubmitCustomProposal {
+                        title: "Drain".to_string(),
+                        description: "Spend escrowed funds".to_string(),
+                        msgs,
+                    },
+                    &coins(100, "inj"),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast::<ContractError>().unwrap(),
+                ContractError::CustomMsgMovesFunds {}
+            ));
+        }
+
+        let escrow = app.wrap().query_balance(&nft, "inj").unwrap();
+        assert_eq!(Uint128::new(100), escrow.amount);
+        let balance = app.wrap().query_balance("grantee", "inj").unwrap();
+        assert!(balance.amount.is_zero());
+    }
+
+    #[test]
+    fn custom_proposal_marked_failed_when_message_errors() {
+        let (mut app, nft, receiver) = setup();
+
+        // 第一条消息成功、第二条失败：整批回滚，第一条的效果也不保留
+        let proposal_id = run_custom_proposal(
+            &mut app,
+            &nft,
+            vec![
+                receive_msg(&receiver, "accept"),
+                receive_msg(&receiver, "reject"),
+            ],
+        );
+
+        assert_eq!(
+            ProposalStatus::Failed,
+            proposal_status(&app, &nft, proposal_id)
+        );
+        let received: Vec<String> = app
+            .wrap()
+            .query_wasm_smart(&receiver, &receiver::QueryMsg::Received {})
+            .unwrap();
+        assert!(received.is_empty());
+
+        // 批量发送消息只能由合约自身调用
+        let res = app.execute_contract(
+            Addr::unchecked("creator"),
+            nft.clone(),
+            &ExecuteMsg::DispatchCustomMessages { proposal_id },
+            &[],
+        );
+        assert!(res.is_err());
+
+        // 失败的提案不能再次执行
+        let res = app.execute_contract(
+            Addr::unchecked("creator"),
+            nft.clone(),
+            &ExecuteMsg::ExecuteProposal { proposal_id },
+            &[],
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn custom_proposal_cannot_dispatch_other_proposals() {
+        let (mut app, nft, receiver) = setup();
+
+        // 尚在投票中的自定义提案
+        app.execute_contract(
+            Addr::unchecked("creator"),
+            nft.clone(),
+            &ExecuteMsg::SubmitCustomProposal {
+                title: "Pending".to_string(),
+                description: "Not yet approved".to_string(),
+                msgs: vec![receive_msg(&receiver, "accept")],
+            },
+            &[],
+        )
+        .unwrap();
+
+        // 通过的提案借自调用发出另一个提案的消息时整批失败
+        let dispatch_other: CosmosMsg = WasmMsg::Execute {
+            contract_addr: nft.to_string(),
+            msg: to_json_binary(&ExecuteMsg::DispatchCustomMessages { proposal_id: 0 }).unwrap(),
+            funds: vec![],
+        }
+        .into();
+        let proposal_id = run_custom_proposal(&mut app, &nft, vec![dispatch_other]);
+
+        assert_eq!(
+            ProposalStatus::Failed,
+            proposal_status(&app, &nft, proposal_id)
+        );
+        assert_eq!(ProposalStatus::Active, proposal_status(&app, &nft, 0));
+        let received: Vec<String> = app
+            .wrap()
+            .query_wasm_smart(&receiver, &receiver::QueryMsg::Received {})
+            .unwrap();
+        assert!(received.is_empty());
+
+        // 重新发出已执行提案自己的消息同样被拒绝
+        let dispatch_self: CosmosMsg = WasmMsg::Execute {
+            contract_addr: nft.to_string(),
+            msg: to_json_binary(&ExecuteMsg::DispatchCustomMessages { proposal_id: 2 }).unwrap(),
+            funds: vec![],
+        }
+        .into();
+        let proposal_id = run_custom_proposal(&mut app, &nft, vec![dispatch_self]);
+        assert_eq!(2, proposal_id);
+        assert_eq!(
+            ProposalStatus::Failed,
+            proposal_status(&app, &nft, proposal_id)
+        );
+    }
+
+    #[test]
+    fn send_nft_to_accepting_contract() {
+        let (mut app, nft, receiver) = setup();
+
+        app.execute_contract(
+            Addr::unchecked("author"),
+            nft.clone(),
+            &ExecuteMsg::SendNft {
+                contract: receiver.to_string(),
+                token_id: "0".to_string(),
+                msg: to_json_binary("accept").unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(receiver.to_string(), owner_of(&app, &nft, "0"));
+        let received: Vec<String> = app
+            .wrap()
+            .query_wasm_smart(&receiver, &receiver::QueryMsg::Received {})
+            .unwrap();
+        assert_eq!(vec!["author:0".to_string()], received);
+    }
+
+    #[test]
+    fn send_nft_rolls_back_when_receiver_rejects() {
+        let (mut app, nft, receiver) = setup();
+
+        let res = app.execute_contract(
+            Addr::unchecked("author"),
+            nft.clone(),
+            &ExecuteMsg::SendNft {
+                contract: receiver.to_string(),
+                token_id: "0".to_string(),
+                msg: to_json_binary("reject").unwrap(),
+            },
+            &[],
+        );
+        assert!(res.is_err());
+        assert_eq!("author", owner_of(&app, &nft, "0"));
+
+        // 非所有者不能发送
+        let res = app.execute_contract(
+            Addr::unchecked("stranger"),
+            nft.clone(),
+            &ExecuteMsg::SendNft {
+                contract: receiver.to_string(),
+                token_id: "0".to_string(),
+                msg: to_json_binary("accept").unwrap(),
+            },
+            &[],
+        );
+        assert!(res.is_err());
+    }
+}
@@ -1,4 +1,9 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, Uint128};
+pub use cw721::{
+    AllNftInfoResponse, Approval, ApprovalResponse, ApprovalsResponse, ContractInfoResponse,
+    Expiration, NftInfoResponse, NumTokensResponse, OperatorResponse, OperatorsResponse,
+    OwnerOfResponse, TokensResponse,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +12,14 @@ pub struct InstantiateMsg {
     pub name: String,
     pub symbol: String,
     pub owner: String,
+    /// 接受的支付代币，第一个作为初始引用费用的计价单位
+    pub accepted_denoms: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {
+    /// 从未记录 cw2 版本的旧合约迁移时使用的支付代币列表，默认为 ["inj"]
+    pub accepted_denoms: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -19,16 +32,41 @@ pub enum ExecuteMsg {
     },
 
     // 数据管理功能
+    /// 创建研究数据 NFT，可设置访问价格和是否公开
+    CreateDataItem {
+        ipfs_hash: String,
+        metadata_uri: String,
+        price: Uint128,
+        /// 价格的计价代币，默认为引用费用的计价单位
+        denom: Option<String>,
+        is_public: bool,
+    },
     /// 更新信息
     UpdateDataItem {
         token_id: String,
         new_ipfs_hash: String,
         new_metadata_uri: String,
     },
+    /// 更新数据的访问价格和公开状态，仅所有者可调用
+    UpdateDataItemTerms {
+        token_id: String,
+        price: Option<Uint128>,
+        denom: Option<String>,
+        is_public: Option<bool>,
+    },
     FreezeData {
         token_id: String,
         freeze: bool,
     },
+    /// 退役数据：保留历史记录，但停止出售新的访问权限
+    RetireData {
+        token_id: String,
+        retire: bool,
+    },
+    /// 销毁 NFT 并清除其所有存储；已冻结或已被引用的数据需通过 DAO 提案销毁
+    Burn {
+        token_id: String,
+    },
 
     // 权限管理
     GrantAccess {
@@ -42,23 +80,44 @@ pub enum ExecuteMsg {
         recipient: String,
         token_id: String,
     },
+    /// 将 NFT 转移给合约并触发其 `ReceiveNft` 回调
+    SendNft {
+        contract: String,
+        token_id: String,
+        msg: Binary,
+    },
     Approve {
         spender: String,
         token_id: String,
+        expires: Option<Expiration>,
+    },
+    Revoke {
+        spender: String,
+        token_id: String,
     },
     ApproveAll {
         operator: String,
+        expires: Option<Expiration>,
     },
     RevokeAll {
         operator: String,
     },
 
-    SetBaseCitationFee {
-        fee: Uint128,
+    // 合约所有权
+    /// 所有者提名新所有者，需由新所有者接受后生效
+    ProposeNewOwner {
+        new_owner: String,
+        expiry: Option<Expiration>,
     },
+    /// 被提名者接受所有权
+    AcceptOwnership {},
+    /// 所有者放弃所有权，合约此后完全由 DAO 提案治理
+    RenounceOwnership {},
 
+    /// 引用论文并支付引用费，`citing_paper_id` 为调用者拥有的引用方论文，记录为引用图中的一条边
     CitePaper {
         paper_id: String,
+        citing_paper_id: Option<String>,
     },
     CreatePaperItem {
         ipfs_hash: String,
@@ -77,10 +136,20 @@ pub enum ExecuteMsg {
         metadata_uri: String,
         title: String,
         description: String,
+        /// 随提案一起支付的评审赏金，由合约托管，评审被接受的评审人平分
+        review_bounty: Option<Coin>,
     },
     SubmitMemberProposal {
         member_address: String,
         action: MemberAction,
+        weight: Option<u64>, // 添加成员时的投票权重，默认为 1
+        title: String,
+        description: String,
+    },
+    /// 提交调整成员投票权重的提案
+    SubmitMemberWeightProposal {
+        member_address: String,
+        weight: u64,
         title: String,
         description: String,
     },
@@ -88,32 +157,208 @@ pub enum ExecuteMsg {
         proposal_id: u64,
         choice: VoteChoice,
     },
+    /// 提交-揭示投票的提交期内提交投票承诺，可以重复提交覆盖之前的承诺
+    /// 承诺为 sha256("{proposal_id}:{voter}:{choice}:{salt}") 的小写十六进制编码，
+    /// `choice` 取 yes / no / no_with_veto / abstain
+    CommitVote {
+        proposal_id: u64,
+        commitment: String,
+    },
+    /// 揭示期内公开投票选择和盐值，与承诺一致时计入票数
+    RevealVote {
+        proposal_id: u64,
+        choice: VoteChoice,
+        salt: String,
+    },
     ExecuteProposal {
         proposal_id: u64,
     },
+    /// 提交销毁已冻结或已被引用的 NFT 的提案，仅所有者或被批准者可调用
+    SubmitBurnProposal {
+        token_id: String,
+        title: String,
+        description: String,
+    },
     UpdateDaoConfig {
         voting_period: Option<u64>,
         approval_threshold: Option<u64>,
         min_members: Option<u64>,
+        quorum: Option<u64>,
+        /// 新的提案押金，数量为 0 表示取消押金
+        proposal_deposit: Option<Coin>,
+        slash_spam_deposits: Option<bool>,
+        /// 提案通过后到可以执行的等待时间（秒），0 表示立即执行
+        execution_delay: Option<u64>,
+        /// 新的否决委员会，成员列表为空表示取消否决委员会
+        veto_council: Option<VetoCouncilMsg>,
+        /// 新的同行评审设置，编辑列表为空表示取消评审
+        review_config: Option<ReviewConfigMsg>,
+        /// 提交-揭示投票的揭示期（秒），0 表示公开投票
+        vote_reveal_period: Option<u64>,
+        /// 新的声誉计分规则
+        reputation_config: Option<ReputationConfig>,
+        /// 新提案快照投票权时使用的权重来源
+        voting_weight_source: Option<VotingWeightSource>,
+    },
+    /// 提交执行 CosmosMsg 的提案，例如调用其他合约
+    /// 只接受不附带资金的 Wasm 消息，资金支出使用金库支出提案
+    SubmitCustomProposal {
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg>,
+    },
+    /// 提交从 DAO 金库支出资金的提案
+    SubmitTreasurySpendProposal {
+        recipient: String,
+        amount: Coin,
+        title: String,
+        description: String,
+    },
+    /// 提案者在投票结束前撤回提案
+    WithdrawProposal {
+        proposal_id: u64,
+    },
+    /// 提案者在投票结束前替换提案的执行数据，已有投票被清空并重新开始投票期
+    /// 已收到评审的文章提案不能修改
+    AmendProposal {
+        proposal_id: u64,
+        execution_data: ExecutionData,
+    },
+    /// 否决委员会成员在投票期或时间锁期间否决提案
+    VetoProposal {
+        proposal_id: u64,
+        reason: String,
+    },
+    /// 投票期结束后按最终票数确定提案结果，任何人都可调用
+    FinalizeProposal {
+        proposal_id: u64,
+    },
+    /// 提案结束后由提案者取回押金
+    ClaimDeposit {
+        proposal_id: u64,
+    },
+    /// 将投票权委托给另一名 DAO 成员，只影响之后创建的提案
+    Delegate {
+        to: String,
+    },
+    /// 取消投票委托
+    Undelegate {},
+    /// 提交修改接受的支付代币列表的提案，不能移除仍被数据项价格或引用费用使用的代币
+    UpdateAcceptedDenoms {
+        denoms: Vec<String>,
+    },
+    /// 提交修改费用配置的提案，未提供的字段保持不变
+    UpdateFees {
+        base_citation_fee: Option<Coin>,
+        citation_dao_share_bps: Option<u64>,
+        access_protocol_fee_bps: Option<u64>,
+    },
+    /// 编辑为评审中的文章提案分配评审人
+    AssignReviewer {
+        proposal_id: u64,
+        reviewer: String,
+    },
+    /// 盲审时评审人在截止时间前提交评审承诺
+    /// 承诺为 sha256("{proposal_id}:{reviewer}:{score}:{recommendation}:{report_ipfs_hash}:{salt}") 的小写十六进制编码，
+    /// `recommendation` 取 accept / minor_revision / major_revision / reject
+    CommitReview {
+        proposal_id: u64,
+        commitment: String,
+    },
+    /// 评审人提交评审，报告本身存放在 IPFS
+    /// 公开评审时在截止时间前直接提交，盲审时在揭示期内带上盐值揭示
+    SubmitReview {
+        proposal_id: u64,
+        score: u8,
+        recommendation: ReviewRecommendation,
+        report_ipfs_hash: String,
+        salt: Option<String>,
+    },
+    /// 编辑或 DAO（通过自定义消息提案调用合约自身）接受评审，被接受的评审人可分得评审赏金
+    AcceptReview {
+        proposal_id: u64,
+        reviewer: String,
+    },
+    /// 结算评审赏金：按份额支付给评审被接受的评审人，剩余部分退还提案者
+    /// 提案结束后，或评审截止时间已过提案仍在评审中时，任何人都可以调用
+    SettleReviewBounty {
+        proposal_id: u64,
+    },
+    /// 内部消息：合约调用自身，一次性发出自定义提案的全部消息
+    /// 只接受合约自身在执行该提案时调用，任一消息失败时整批回滚
+    DispatchCustomMessages {
+        proposal_id: u64,
     },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    // NFT 基础查询
+    // cw721 标准查询
+    /// 返回 token 的所有者及其批准列表: `OwnerOfResponse`
     OwnerOf {
         token_id: String,
+        include_expired: Option<bool>,
     },
-    TokenInfo {
+    /// 返回 spender 对 token 的批准: `ApprovalResponse`
+    Approval {
         token_id: String,
+        spender: String,
+        include_expired: Option<bool>,
     },
-    AllTokens {
+    /// 返回 token 的所有批准: `ApprovalsResponse`
+    Approvals {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    /// 返回所有者对操作员的授权: `OperatorResponse`
+    Operator {
+        owner: String,
+        operator: String,
+        include_expired: Option<bool>,
+    },
+    /// 列出所有者的所有操作员: `OperatorsResponse`
+    AllOperators {
+        owner: String,
+        include_expired: Option<bool>,
         start_after: Option<String>,
         limit: Option<u32>,
     },
     NumTokens {},
     ContractInfo {},
+    /// 返回 token 元数据，`DataItem` 作为扩展字段: `NftInfoResponse<DataItem>`
+    NftInfo {
+        token_id: String,
+    },
+    /// 同时返回 `NftInfo` 和 `OwnerOf`: `AllNftInfoResponse<DataItem>`
+    AllNftInfo {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    /// 列出地址拥有的 token: `TokensResponse`
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// 列出所有 token: `TokensResponse`
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    // NFT 扩展查询
+    TokenInfo {
+        token_id: String,
+    },
+    /// 分页列出地址拥有的 token 及其数据项: `TokensByOwnerResponse`
+    TokensByOwner {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// 查询合约所有者和待接受的所有权转移: `ContractOwnerResponse`
+    GetContractOwner {},
 
     // 研究数据特定查询
     GetDataItem {
@@ -132,10 +377,33 @@ pub enum QueryMsg {
     GetCitations {
         paper_id: String,
     },
+    /// 分页列出论文引用的论文: `CitationEdgesResponse`
+    GetReferences {
+        paper_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// 分页列出引用该论文的论文: `CitationEdgesResponse`
+    GetCitedBy {
+        paper_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// 查询论文的引用计数: `CitationCountsResponse`
+    GetCitationCounts {
+        paper_id: String,
+    },
     GetPaperDoi {
         paper_id: String,
     },
+    /// 通过 DOI 查找论文 token: `TokenInfoResponse`
+    GetPaperByDoi {
+        doi: String,
+    },
     GetBaseCitationFee {},
+    GetPaymentConfig {},
+    /// 查询当前费用配置: `FeeConfigResponse`
+    GetFeeConfig {},
 
     // DAO 查询
     GetDaoMembers {},
@@ -155,9 +423,53 @@ pub enum QueryMsg {
     GetVoteCount {
         proposal_id: u64,
     },
+    /// 列出提交-揭示投票中提交承诺但未在揭示期内揭示的成员: `VoteNoShowsResponse`
+    GetVoteNoShows {
+        proposal_id: u64,
+    },
+    /// 列出尚未退还或没收的提案押金: `OutstandingDepositsResponse`
+    GetOutstandingDeposits {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
     GetMemberVotingPower {
         member: String,
     },
+    /// 列出当前委托给该成员的委托人: `DelegatorsResponse`
+    GetDelegators {
+        member: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// 列出文章提案收到的评审: `ReviewsResponse`
+    GetReviews {
+        proposal_id: u64,
+    },
+    /// 分页列出评审人尚未完成且未过期的评审任务: `ReviewAssignmentsResponse`
+    GetReviewerAssignments {
+        reviewer: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// 查询 DAO 金库各代币余额: `TreasuryBalanceResponse`
+    TreasuryBalance {},
+    /// 查询研究者的声誉，分数衰减到当前时间: `ReputationResponse`
+    GetReputation {
+        address: String,
+    },
+    /// 按当前分数从高到低列出声誉排行，最多 100 条: `ReputationLeaderboardResponse`
+    GetReputationLeaderboard {
+        limit: Option<u32>,
+    },
+    /// 列出提案收到的否决: `VetoesResponse`
+    GetVetoes {
+        proposal_id: u64,
+    },
+    /// 按提案快照计算成员当前可行使的投票权: `EffectivePowerResponse`
+    GetEffectivePower {
+        proposal_id: u64,
+        member: String,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -172,12 +484,14 @@ pub struct DataItem {
     pub owner: Addr,
     pub ipfs_hash: String,
     pub price: Uint128,
+    pub denom: String,
     pub is_public: bool,
     pub total_earned: Uint128,
     pub created_at: u64,
     pub last_updated: u64,
     pub metadata_uri: String,
     pub is_frozen: bool,
+    pub is_retired: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -195,20 +509,21 @@ pub struct TokenInfoResponse {
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct ContractInfoResponse {
-    pub name: String,
-    pub symbol: String,
-    pub owner: Addr,
+pub struct TokensByOwnerResponse {
+    pub tokens: Vec<TokenInfoResponse>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct OwnerOfResponse {
+pub struct ContractOwnerResponse {
+    /// 放弃所有权后为合约自身地址，表示合约完全由 DAO 治理
     pub owner: Addr,
+    pub pending_owner: Option<PendingOwner>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct NumTokensResponse {
-    pub count: u64,
+pub struct PendingOwner {
+    pub owner: Addr,
+    pub expires: Expiration,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -218,17 +533,167 @@ pub struct Citation {
     pub timestamp: u64,
 }
 
+/// 引用图中的一条边：`citing_paper_id` 引用了 `cited_paper_id`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CitationEdge {
+    pub citing_paper_id: String,
+    pub cited_paper_id: String,
+    pub citer: Addr,
+    pub timestamp: u64,
+}
+
+/// 论文在引用图中的计数
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct CitationCounts {
+    pub cited_by: u64,   // 被多少篇论文引用
+    pub references: u64, // 引用了多少篇论文
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CitationEdgesResponse {
+    pub edges: Vec<CitationEdge>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CitationCountsResponse {
+    pub paper_id: String,
+    pub counts: CitationCounts,
+    pub paid_citations: u64, // 支付过引用费的次数，包括没有指明引用方论文的引用
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct BaseCitationFeeResponse {
     pub fee: Uint128,
+    pub denom: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentConfig {
+    pub accepted_denoms: Vec<String>, // 接受的支付代币
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentConfigResponse {
+    pub config: PaymentConfig,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeConfig {
+    pub base_citation_fee: Coin,      // 最低引用费用及其计价代币
+    pub citation_dao_share_bps: u64,  // 引用费用中进入 DAO 金库的比例（基点）
+    pub access_protocol_fee_bps: u64, // 数据访问费用中进入 DAO 金库的比例（基点）
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeConfigResponse {
+    pub config: FeeConfig,
 }
 
 // DAO 相关数据结构
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct DaoConfig {
-    pub voting_period: u64,      // 投票期限（秒）
-    pub approval_threshold: u64, // 通过阈值（百分比，如51表示51%）
-    pub min_members: u64,        // 最小成员数量
+    pub voting_period: u64,                       // 投票期限（秒）
+    pub approval_threshold: u64,                  // 通过阈值（赞成票占赞成与反对票的百分比）
+    pub quorum: u64,      // 法定人数（参与投票含弃权占有资格投票者的百分比）
+    pub min_members: u64, // 最小成员数量
+    pub proposal_deposit: Option<Coin>, // 提交提案需缴纳的押金，None 表示无需押金
+    pub slash_spam_deposits: bool, // 被认定为垃圾提案时押金是否归 DAO 所有
+    pub execution_delay: u64, // 提案通过后到可以执行的等待时间（秒）
+    pub veto_council: Option<VetoCouncil>, // 可在投票期或时间锁期间否决提案的委员会
+    pub review_config: Option<ReviewConfig>, // 文章提案进入投票前的同行评审，None 表示不评审
+    pub vote_reveal_period: u64, // 提交-揭示投票的揭示期（秒），0 表示公开投票
+    pub reputation_config: ReputationConfig, // 声誉计分规则
+    pub voting_weight_source: VotingWeightSource, // 提案快照投票权时使用的权重来源
+}
+
+/// 声誉计分规则：每类事件增加的分数，以及分数的衰减半衰期
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReputationConfig {
+    pub paper_points: u64,    // 发布一篇论文
+    pub citation_points: u64, // 论文被引用一次
+    pub review_points: u64,   // 完成一份评审
+    pub proposal_points: u64, // 提案通过
+    pub half_life: u64,       // 分数衰减一半所需的时间（秒），0 表示不衰减
+    pub points_per_vote: u64, // 按声誉计票时每多少分折算一票
+}
+
+/// 投票权重来源
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum VotingWeightSource {
+    MemberWeight, // 只使用成员权重
+    Reputation,   // 成员权重加上声誉折算的票数
+}
+
+/// 研究者的声誉记录，计数不衰减，`score` 为 `updated_at` 时的分数
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Reputation {
+    pub address: Addr,
+    pub papers_published: u64,
+    pub citations_received: u64,
+    pub reviews_completed: u64,
+    pub proposals_passed: u64,
+    pub score: u64,
+    pub updated_at: u64,
+}
+
+/// 同行评审设置：编辑分配评审人，收到 `required_reviews` 份评审后文章提案进入投票
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReviewConfig {
+    pub editors: Vec<Addr>,
+    pub required_reviews: u64,
+    pub review_period: u64, // 分配后提交评审的期限（秒）
+    pub reveal_period: u64, // 盲审揭示期（秒），0 表示公开提交评审
+}
+
+/// 配置同行评审时提交的参数，编辑地址在保存前校验
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReviewConfigMsg {
+    pub editors: Vec<String>,
+    pub required_reviews: u64,
+    pub review_period: u64,
+    pub reveal_period: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum ReviewRecommendation {
+    Accept,
+    MinorRevision,
+    MajorRevision,
+    Reject,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Review {
+    pub reviewer: Addr,
+    pub score: u8, // 1 到 10
+    pub recommendation: ReviewRecommendation,
+    pub report_ipfs_hash: String,
+    pub submitted_at: u64,
+    pub accepted: bool, // 是否被编辑或 DAO 接受，接受后可分得评审赏金
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReviewAssignment {
+    pub proposal_id: u64,
+    pub reviewer: Addr,
+    pub assigned_by: Addr,
+    pub deadline: u64,
+}
+
+/// 否决委员会：达到 `threshold` 名成员否决时提案被否决
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VetoCouncil {
+    pub name: String,
+    pub members: Vec<Addr>,
+    pub threshold: u64,
+}
+
+/// 配置否决委员会时提交的参数，成员地址在保存前校验
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VetoCouncilMsg {
+    pub name: String,
+    pub members: Vec<String>,
+    pub threshold: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -242,6 +707,42 @@ pub struct Proposal {
     pub voting_end: u64,
     pub status: ProposalStatus,
     pub execution_data: Option<ExecutionData>,
+    pub deposit: Option<ProposalDeposit>,
+    pub snapshot_height: u64,            // 投票者快照的区块高度
+    pub executable_at: Option<u64>,      // 通过后可以执行的时间，时间锁期间结果可见但不能执行
+    pub execution_deadline: Option<u64>, // 通过后执行的截止时间，过后提案不能再执行
+    pub review_deadline: Option<u64>,    // 评审阶段的截止时间，过后仍未进入投票的提案过期
+    pub vote_reveal_period: u64,         // 创建时的投票揭示期，0 表示公开投票
+    pub reveal_end: Option<u64>,         // 当前揭示期的截止时间
+    pub review_bounty: Option<ReviewBounty>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalDeposit {
+    pub amount: Coin,
+    pub status: DepositStatus,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum DepositStatus {
+    Held,     // 合约托管中
+    Refunded, // 已退还提案者
+    Slashed,  // 垃圾提案，押金归 DAO 所有
+}
+
+/// 文章提案托管的评审赏金，每个评审名额分得 `amount / reviewer_slots`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReviewBounty {
+    pub amount: Coin,
+    pub reviewer_slots: u64, // 提交时要求的评审数量
+    pub deadline: u64,       // 最晚的评审截止时间，过后仍在评审中可结算退还
+    pub status: ReviewBountyStatus,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum ReviewBountyStatus {
+    Escrowed, // 合约托管中
+    Settled,  // 已支付给评审人并退还剩余部分
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -249,16 +750,28 @@ pub enum ProposalType {
     ArticlePublication,
     AddMember,
     RemoveMember,
+    UpdateMemberWeight,
     UpdateConfig,
+    UpdateDenoms,
+    UpdateFees,
+    BurnToken,
+    Custom,
+    TreasurySpend,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub enum ProposalStatus {
-    Active,   // 投票中
-    Passed,   // 通过
-    Rejected, // 拒绝
-    Executed, // 已执行
-    Expired,  // 已过期
+    Active,       // 投票中
+    Passed,       // 通过
+    Rejected,     // 拒绝
+    Executed,     // 已执行
+    Expired,      // 已过期
+    Vetoed,       // 被否决委员会否决
+    Withdrawn,    // 被提案者撤回
+    Failed,       // 执行时有消息失败
+    UnderReview,  // 同行评审中，评审完成后进入投票
+    ReviewReveal, // 盲审揭示期
+    VoteReveal,   // 提交-揭示投票的揭示期
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -272,6 +785,7 @@ pub struct Vote {
 pub enum VoteChoice {
     Yes,
     No,
+    NoWithVeto, // 反对并认定为垃圾提案
     Abstain,
 }
 
@@ -279,6 +793,7 @@ pub enum VoteChoice {
 pub struct VoteCount {
     pub yes: u64,
     pub no: u64,
+    pub no_with_veto: u64,
     pub abstain: u64,
     pub total_eligible: u64,
 }
@@ -293,10 +808,32 @@ pub enum ExecutionData {
     MemberChange {
         member_address: String,
         action: MemberAction,
+        weight: Option<u64>, // 添加成员时的投票权重
+    },
+    MemberWeightUpdate {
+        member_address: String,
+        weight: u64,
     },
     ConfigUpdate {
         new_config: DaoConfig,
     },
+    DenomsUpdate {
+        accepted_denoms: Vec<String>,
+    },
+    FeesUpdate {
+        new_config: FeeConfig,
+    },
+    BurnToken {
+        token_id: String,
+        owner: Addr, // 提交提案时的所有者，执行时需仍为该所有者
+    },
+    Custom {
+        msgs: Vec<CosmosMsg>,
+    },
+    TreasurySpend {
+        recipient: String,
+        amount: Coin,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -324,7 +861,62 @@ pub struct VotingPowerResponse {
     pub is_member: bool,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VoteNoShowsResponse {
+    pub no_shows: Vec<Addr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReviewsResponse {
+    pub reviews: Vec<Review>,
+    pub no_shows: Vec<Addr>, // 盲审中提交承诺但未揭示的评审人
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReviewAssignmentsResponse {
+    pub assignments: Vec<ReviewAssignment>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TreasuryBalanceResponse {
+    pub balances: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Veto {
+    pub member: Addr,
+    pub reason: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VetoesResponse {
+    pub vetoes: Vec<Veto>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DelegatorsResponse {
+    pub delegate: Option<Addr>, // 该成员自己的受托人
+    pub delegators: Vec<Addr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EffectivePowerResponse {
+    pub own_power: u64,       // 快照中的自身权重
+    pub delegated_power: u64, // 尚未直接投票的委托人转来的权重
+    pub total_power: u64,
+}
+
 // Additional DAO query response structures
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReputationResponse {
+    pub reputation: Reputation,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReputationLeaderboardResponse {
+    pub entries: Vec<Reputation>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct DaoConfigResponse {
     pub config: DaoConfig,
@@ -340,6 +932,19 @@ pub struct VoteResponse {
     pub vote: Option<Vote>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OutstandingDeposit {
+    pub proposal_id: u64,
+    pub depositor: Addr,
+    pub amount: Coin,
+    pub voting_ended: bool, // 投票已结束，提案者可以尝试取回
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OutstandingDepositsResponse {
+    pub deposits: Vec<OutstandingDeposit>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct VoteCountResponse {
     pub vote_count: VoteCount,
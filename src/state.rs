@@ -1,38 +1,147 @@
 use crate::msg::{
-    AccessLevel, Citation, DaoConfig, DataItem, DataVersion, Proposal, Vote, VoteCount,
+    AccessLevel, Citation, CitationCounts, CitationEdge, DaoConfig, DataItem, DataVersion,
+    FeeConfig, PaymentConfig, PendingOwner, Proposal, Reputation, Review, ReviewAssignment, Vote,
+    VoteCount,
 };
-use cosmwasm_std::{Addr, Uint128};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw721::Expiration;
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 // 合约基础信息
 pub const CONTRACT_NAME: Item<String> = Item::new("contract_name");
 pub const CONTRACT_SYMBOL: Item<String> = Item::new("contract_symbol");
-pub const CONTRACT_OWNER: Item<Addr> = Item::new("contract_owner");
+pub const CONTRACT_OWNER: Item<Addr> = Item::new("contract_owner"); // 放弃所有权后为空
+pub const PENDING_OWNER: Item<PendingOwner> = Item::new("pending_owner");
+
+// 支付配置
+pub const PAYMENT_CONFIG: Item<PaymentConfig> = Item::new("payment_config");
+
+// 费用配置，只能通过 DAO 提案修改
+pub const FEE_CONFIG: Item<FeeConfig> = Item::new("fee_config");
+// 各计价代币下价格不为 0 的数据项数量，仍被使用的代币不能从接受列表中移除
+pub const LISTED_DENOMS: Map<&str, u64> = Map::new("listed_denoms");
 
 // Token 计数器
 pub const TOKEN_ID_COUNTER: Item<u64> = Item::new("token_id_counter");
 pub const TOKEN_COUNT: Item<u64> = Item::new("token_count");
 
 // NFT 所有权和批准
-pub const TOKEN_OWNERS: Map<&str, Addr> = Map::new("token_owners");
-pub const TOKEN_APPROVALS: Map<&str, Addr> = Map::new("token_approvals");
-pub const OPERATOR_APPROVALS: Map<(&str, &str), bool> = Map::new("operator_approvals");
+pub const TOKEN_OWNERS: IndexedMap<&str, Addr, TokenOwnerIndexes> = IndexedMap::new(
+    "token_owners",
+    TokenOwnerIndexes {
+        owner: MultiIndex::new(
+            |_pk, owner| owner.clone(),
+            "token_owners",
+            "token_owners__owner",
+        ),
+    },
+);
+pub const TOKEN_APPROVALS: Map<(&str, &str), Expiration> = Map::new("spender_approvals");
+pub const OPERATOR_APPROVALS: Map<(&str, &str), Expiration> = Map::new("operator_expirations");
 
 // 研究数据特定存储
 pub const DATA_ITEMS: Map<&str, DataItem> = Map::new("data_items");
 pub const DATA_VERSIONS: Map<&str, Vec<DataVersion>> = Map::new("data_versions");
 pub const ACCESS_CONTROLS: Map<(&str, &str), AccessLevel> = Map::new("access_controls");
-pub const AUTHORIZED_USERS: Map<&str, Vec<Addr>> = Map::new("authorized_users");
+pub const AUTHORIZED_USERS: Map<(&str, &Addr), Empty> = Map::new("token_authorized_users");
 
 // 论文特定存储
 pub const CITATIONS: Map<&str, Vec<Citation>> = Map::new("citations");
 pub const PAPER_DOIS: Map<&str, String> = Map::new("paper_dois");
-pub const BASE_CITATION_FEE: Item<Uint128> = Item::new("base_citation_fee");
+pub const DOI_INDEX: Map<&str, String> = Map::new("doi_index"); // 规范化 DOI -> token ID
+
+// 引用图：(引用方论文, 被引论文) -> 边，按被引论文建立索引以查询被引列表
+pub const CITATION_EDGES: IndexedMap<(&str, &str), CitationEdge, CitationEdgeIndexes> =
+    IndexedMap::new(
+        "citation_edges",
+        CitationEdgeIndexes {
+            cited: MultiIndex::new(
+                |_pk, edge| edge.cited_paper_id.clone(),
+                "citation_edges",
+                "citation_edges__cited",
+            ),
+        },
+    );
+pub const CITATION_COUNTS: Map<&str, CitationCounts> = Map::new("citation_counts");
+
+// 同行评审：任务按 (评审人, 提案 ID) 存储以便查询评审人的待办，提交评审后删除
+pub const REVIEW_ASSIGNMENTS: Map<(&Addr, u64), ReviewAssignment> = Map::new("review_assignments");
+pub const REVIEWS: Map<(u64, &Addr), Review> = Map::new("reviews");
+
+// 提交-揭示：揭示前只保存承诺哈希，揭示期结束仍未揭示的评审人记为缺席
+pub const REVIEW_COMMITMENTS: Map<(u64, &Addr), String> = Map::new("review_commitments");
+pub const REVIEW_NO_SHOWS: Map<(u64, &Addr), Empty> = Map::new("review_no_shows");
+pub const VOTE_COMMITMENTS: Map<(u64, &Addr), String> = Map::new("vote_commitments");
+pub const VOTE_NO_SHOWS: Map<(u64, &Addr), Empty> = Map::new("vote_no_shows");
+
+// 研究者声誉：发表、被引用、评审和提案通过的记录
+pub const REPUTATION: Map<&Addr, Reputation> = Map::new("reputation");
+// 排行榜候选：有声誉记录的地址中分数最高的一部分，数量有上限
+pub const REPUTATION_LEADERBOARD: Item<Vec<Addr>> = Item::new("reputation_leaderboard");
+// 经 DAO 提案批准发表的论文：token ID -> 提案 ID，只有这些论文计入声誉
+pub const APPROVED_PAPERS: Map<&str, u64> = Map::new("approved_papers");
+
+// DAO 金库：合约中归 DAO 所有的资金，按代币记账
+pub const TREASURY: Map<&str, Uint128> = Map::new("treasury");
 
 // DAO 存储
-pub const DAO_MEMBERS: Map<&str, bool> = Map::new("dao_members");
+pub const DAO_MEMBERS: Map<&str, u64> = Map::new("dao_members"); // 成员地址 -> 投票权重
 pub const DAO_CONFIG: Item<DaoConfig> = Item::new("dao_config");
 pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
 pub const PROPOSAL_COUNTER: Item<u64> = Item::new("proposal_counter");
+// 正在发出消息批次的自定义提案 ID，只在执行提案与批次自调用之间存在
+pub const PENDING_DISPATCH: Item<u64> = Item::new("pending_dispatch");
 pub const VOTES: Map<(u64, &str), Vote> = Map::new("votes");
+pub const PROPOSAL_VOTERS: Map<(u64, &str), u64> = Map::new("proposal_voters"); // 提案创建时的投票者快照 -> 投票权
 pub const VOTE_COUNTS: Map<u64, VoteCount> = Map::new("vote_counts");
+pub const VETOES: Map<(u64, &Addr), String> = Map::new("vetoes"); // (提案 ID, 委员会成员) -> 否决理由
+
+// 投票委托：委托人 -> 受托人
+pub const DELEGATIONS: IndexedMap<&str, Addr, DelegationIndexes> = IndexedMap::new(
+    "delegations",
+    DelegationIndexes {
+        delegate: MultiIndex::new(
+            |_pk, delegate| delegate.clone(),
+            "delegations",
+            "delegations__delegate",
+        ),
+    },
+);
+pub const PROPOSAL_DELEGATIONS: Map<(u64, &str), Addr> = Map::new("proposal_delegations"); // 提案创建时的委托快照
+pub const PROPOSAL_POWER: Map<(u64, &str), u64> = Map::new("proposal_power"); // 成员持有的投票权：自身权重加上尚未投票的委托人沿委托链转来的权重
+
+/// `TOKEN_OWNERS` 的二级索引，按所有者列出 token
+pub struct TokenOwnerIndexes<'a> {
+    pub owner: MultiIndex<'a, Addr, Addr, String>,
+}
+
+impl<'a> IndexList<Addr> for TokenOwnerIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Addr>> + '_> {
+        let v: Vec<&dyn Index<Addr>> = vec![&self.owner];
+        Box::new(v.into_iter())
+    }
+}
+
+/// `CITATION_EDGES` 的二级索引，按被引论文列出引用方
+pub struct CitationEdgeIndexes<'a> {
+    pub cited: MultiIndex<'a, String, CitationEdge, (String, String)>,
+}
+
+impl<'a> IndexList<CitationEdge> for CitationEdgeIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<CitationEdge>> + '_> {
+        let v: Vec<&dyn Index<CitationEdge>> = vec![&self.cited];
+        Box::new(v.into_iter())
+    }
+}
+
+/// `DELEGATIONS` 的二级索引，按受托人列出委托人
+pub struct DelegationIndexes<'a> {
+    pub delegate: MultiIndex<'a, Addr, Addr, String>,
+}
+
+impl<'a> IndexList<Addr> for DelegationIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Addr>> + '_> {
+        let v: Vec<&dyn Index<Addr>> = vec![&self.delegate];
+        Box::new(v.into_iter())
+    }
+}